@@ -0,0 +1,393 @@
+use crate::{
+    OkLinkClient,
+    batch::ByAddress,
+    error::Result,
+    params::{Closest, ProtocolType},
+    resp::{
+        AddressSummary, BalanceMulti, OkLinkBalanceDetail, OkLinkBalancePage, OkLinkResp,
+        PublishTxInfo,
+    },
+    types::{
+        AddressTransactionList, BlockChainInfo, BlockFills, BlockHeightByTime, BlockList,
+        BlockTransactionList, BlockTransactionListMulti, BlockchainSummary, InscriptionOk,
+        InternalTransaction, InternalTransactionList, NormalTransaction, NormalTransactionList,
+        TokenTransaction, TokenTransactionList, UtxoList,
+    },
+};
+use serde_json::Value;
+use std::future::Future;
+
+/// `OkLinkApi` 抽象了 `OkLinkClient` 的全部接口。
+///
+/// 业务代码依赖该 trait 而不是具体的 `OkLinkClient`，单元测试中即可替换为
+/// [`MockOkLinkApi`](crate::mock::MockOkLinkApi)，无需访问网络。
+/// 各方法的含义与 `OkLinkClient` 上的同名方法一致。
+pub trait OkLinkApi: Send + Sync {
+    fn get_token_price_market_data(&self) -> impl Future<Output = Result<Value>> + Send;
+
+    fn get_address_summary_oklink(
+        &self,
+        address: &str,
+    ) -> impl Future<Output = Result<OkLinkResp<AddressSummary>>> + Send;
+
+    fn get_address_balance_oklink_multi(
+        &self,
+        address: &str,
+    ) -> impl Future<Output = Result<OkLinkResp<BalanceMulti>>> + Send;
+
+    fn get_address_balance_batch(
+        &self,
+        addresses: &[&str],
+    ) -> impl Future<Output = Result<BalanceMulti>> + Send;
+
+    fn get_address_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> impl Future<Output = Result<ByAddress<NormalTransaction>>> + Send;
+
+    fn get_address_normal_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> impl Future<Output = Result<ByAddress<NormalTransaction>>> + Send;
+
+    fn get_address_internal_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> impl Future<Output = Result<ByAddress<InternalTransaction>>> + Send;
+
+    fn get_address_token_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        protocol_type: ProtocolType,
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> impl Future<Output = Result<ByAddress<TokenTransaction>>> + Send;
+
+    fn get_address_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> impl Future<Output = Result<OkLinkResp<AddressTransactionList>>> + Send;
+
+    fn get_address_normal_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> impl Future<Output = Result<OkLinkResp<NormalTransactionList>>> + Send;
+
+    fn get_address_internal_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> impl Future<Output = Result<OkLinkResp<InternalTransactionList>>> + Send;
+
+    fn get_address_token_transaction_list(
+        &self,
+        address: &str,
+        protocol_type: ProtocolType,
+        page: usize,
+        limit: usize,
+    ) -> impl Future<Output = Result<OkLinkResp<TokenTransactionList>>> + Send;
+
+    fn get_brc20_balance_oklink(
+        &self,
+        address: &str,
+        page: usize,
+        page_size: usize,
+    ) -> impl Future<Output = Result<OkLinkResp<OkLinkBalancePage>>> + Send;
+
+    fn get_brc20_token_detail_oklink(
+        &self,
+        address: &str,
+        tick: &str,
+        page: usize,
+        page_size: usize,
+    ) -> impl Future<Output = Result<OkLinkResp<OkLinkBalanceDetail>>> + Send;
+
+    fn address_inscription_list_oklink(
+        &self,
+        address: &str,
+        page: usize,
+        page_size: usize,
+    ) -> impl Future<Output = Result<Value>> + Send;
+
+    fn publish_tx(&self, signed_tx: &str) -> impl Future<Output = Result<PublishTxInfo>> + Send;
+
+    fn get_btc_utxo_oklink(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> impl Future<Output = Result<OkLinkResp<UtxoList>>> + Send;
+
+    fn get_utxo_by_inscription_id_oklink(
+        &self,
+        inscription_id: &str,
+        inscription_number: &str,
+    ) -> impl Future<Output = Result<OkLinkResp<InscriptionOk>>> + Send;
+
+    fn get_btc_transaction_list_oklink(
+        &self,
+        height: usize,
+        page: usize,
+        limit: usize,
+    ) -> impl Future<Output = Result<OkLinkResp<BlockTransactionList>>> + Send;
+
+    fn get_btc_transaction_list_multi_oklink(
+        &self,
+        from_block: usize,
+        to_block: usize,
+        page: usize,
+        limit: usize,
+    ) -> impl Future<Output = Result<OkLinkResp<BlockTransactionListMulti>>> + Send;
+
+    fn get_block_fills(
+        &self,
+        height: usize,
+    ) -> impl Future<Output = Result<OkLinkResp<BlockFills>>> + Send;
+
+    fn get_block_list(
+        &self,
+        page: usize,
+        limit: usize,
+    ) -> impl Future<Output = Result<OkLinkResp<BlockList>>> + Send;
+
+    fn get_block_height_by_time(
+        &self,
+        time: u64,
+        closest: Closest,
+    ) -> impl Future<Output = Result<OkLinkResp<BlockHeightByTime>>> + Send;
+
+    fn get_chain_info(&self) -> impl Future<Output = Result<OkLinkResp<BlockChainInfo>>> + Send;
+
+    fn get_blockchain_summary(
+        &self,
+    ) -> impl Future<Output = Result<OkLinkResp<BlockchainSummary>>> + Send;
+}
+
+impl OkLinkApi for OkLinkClient {
+    async fn get_token_price_market_data(&self) -> Result<Value> {
+        OkLinkClient::get_token_price_market_data(self).await
+    }
+
+    async fn get_address_summary_oklink(
+        &self,
+        address: &str,
+    ) -> Result<OkLinkResp<AddressSummary>> {
+        OkLinkClient::get_address_summary_oklink(self, address).await
+    }
+
+    async fn get_address_balance_oklink_multi(
+        &self,
+        address: &str,
+    ) -> Result<OkLinkResp<BalanceMulti>> {
+        OkLinkClient::get_address_balance_oklink_multi(self, address).await
+    }
+
+    async fn get_address_balance_batch(&self, addresses: &[&str]) -> Result<BalanceMulti> {
+        OkLinkClient::get_address_balance_batch(self, addresses).await
+    }
+
+    async fn get_address_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<NormalTransaction>> {
+        OkLinkClient::get_address_transaction_list_batch(
+            self,
+            addresses,
+            start_block_height,
+            end_block_height,
+        )
+        .await
+    }
+
+    async fn get_address_normal_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<NormalTransaction>> {
+        OkLinkClient::get_address_normal_transaction_list_batch(
+            self,
+            addresses,
+            start_block_height,
+            end_block_height,
+        )
+        .await
+    }
+
+    async fn get_address_internal_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<InternalTransaction>> {
+        OkLinkClient::get_address_internal_transaction_list_batch(
+            self,
+            addresses,
+            start_block_height,
+            end_block_height,
+        )
+        .await
+    }
+
+    async fn get_address_token_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        protocol_type: ProtocolType,
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<TokenTransaction>> {
+        OkLinkClient::get_address_token_transaction_list_batch(
+            self,
+            addresses,
+            protocol_type,
+            start_block_height,
+            end_block_height,
+        )
+        .await
+    }
+
+    async fn get_address_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<AddressTransactionList>> {
+        OkLinkClient::get_address_transaction_list(self, address, page, limit).await
+    }
+
+    async fn get_address_normal_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<NormalTransactionList>> {
+        OkLinkClient::get_address_normal_transaction_list(self, address, page, limit).await
+    }
+
+    async fn get_address_internal_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<InternalTransactionList>> {
+        OkLinkClient::get_address_internal_transaction_list(self, address, page, limit).await
+    }
+
+    async fn get_address_token_transaction_list(
+        &self,
+        address: &str,
+        protocol_type: ProtocolType,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<TokenTransactionList>> {
+        OkLinkClient::get_address_token_transaction_list(self, address, protocol_type, page, limit)
+            .await
+    }
+
+    async fn get_brc20_balance_oklink(
+        &self,
+        address: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<OkLinkResp<OkLinkBalancePage>> {
+        OkLinkClient::get_brc20_balance_oklink(self, address, page, page_size).await
+    }
+
+    async fn get_brc20_token_detail_oklink(
+        &self,
+        address: &str,
+        tick: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<OkLinkResp<OkLinkBalanceDetail>> {
+        OkLinkClient::get_brc20_token_detail_oklink(self, address, tick, page, page_size).await
+    }
+
+    async fn address_inscription_list_oklink(
+        &self,
+        address: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<Value> {
+        OkLinkClient::address_inscription_list_oklink(self, address, page, page_size).await
+    }
+
+    async fn publish_tx(&self, signed_tx: &str) -> Result<PublishTxInfo> {
+        OkLinkClient::publish_tx(self, signed_tx).await
+    }
+
+    async fn get_btc_utxo_oklink(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<UtxoList>> {
+        OkLinkClient::get_btc_utxo_oklink(self, address, page, limit).await
+    }
+
+    async fn get_utxo_by_inscription_id_oklink(
+        &self,
+        inscription_id: &str,
+        inscription_number: &str,
+    ) -> Result<OkLinkResp<InscriptionOk>> {
+        OkLinkClient::get_utxo_by_inscription_id_oklink(self, inscription_id, inscription_number)
+            .await
+    }
+
+    async fn get_btc_transaction_list_oklink(
+        &self,
+        height: usize,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<BlockTransactionList>> {
+        OkLinkClient::get_btc_transaction_list_oklink(self, height, page, limit).await
+    }
+
+    async fn get_btc_transaction_list_multi_oklink(
+        &self,
+        from_block: usize,
+        to_block: usize,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<BlockTransactionListMulti>> {
+        OkLinkClient::get_btc_transaction_list_multi_oklink(self, from_block, to_block, page, limit)
+            .await
+    }
+
+    async fn get_block_fills(&self, height: usize) -> Result<OkLinkResp<BlockFills>> {
+        OkLinkClient::get_block_fills(self, height).await
+    }
+
+    async fn get_block_list(&self, page: usize, limit: usize) -> Result<OkLinkResp<BlockList>> {
+        OkLinkClient::get_block_list(self, page, limit).await
+    }
+
+    async fn get_block_height_by_time(
+        &self,
+        time: u64,
+        closest: Closest,
+    ) -> Result<OkLinkResp<BlockHeightByTime>> {
+        OkLinkClient::get_block_height_by_time(self, time, closest).await
+    }
+
+    async fn get_chain_info(&self) -> Result<OkLinkResp<BlockChainInfo>> {
+        OkLinkClient::get_chain_info(self).await
+    }
+
+    async fn get_blockchain_summary(&self) -> Result<OkLinkResp<BlockchainSummary>> {
+        OkLinkClient::get_blockchain_summary(self).await
+    }
+}
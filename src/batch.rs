@@ -0,0 +1,453 @@
+//! 多地址批量查询。
+//!
+//! OkLink 的 `*-multi` 接口每次只接受有限个地址（见 [`MAX_BALANCE_ADDRESSES`] 与
+//! [`MAX_TRANSACTION_ADDRESSES`]）。这里的方法接受任意数量的地址：先去重，再按上限分块，
+//! 各块并发请求（最多 [`MAX_CONCURRENT_CHUNKS`] 块同时进行）并遍历全部页面，
+//! 最后按地址归类。任意一块失败时整个调用返回该错误。
+//!
+//! ```ignore
+//! let txs = client
+//!     .get_address_transaction_list_batch(&addresses, 18809970, 18819970)
+//!     .await?;
+//! for (address, txs) in &txs {
+//!     println!("{}: {} txs", address, txs.len());
+//! }
+//! ```
+
+use crate::{
+    OkLinkClient,
+    error::Result,
+    page::Paginated,
+    params::{
+        AddressInternalTransactionListMultiParams, AddressNormalTransactionListMultiParams,
+        AddressTokenTransactionListMultiParams, AddressTransactionListMultiParams,
+        BalanceMultiParams, EndpointParams, MAX_BALANCE_ADDRESSES, MAX_LIMIT,
+        MAX_TRANSACTION_ADDRESSES, ProtocolType,
+    },
+    resp::{BalanceMulti, OkLinkResp},
+    stream::paginate,
+    types::{InternalTransaction, NormalTransaction, TokenTransaction},
+};
+use futures::{StreamExt, TryStreamExt, stream};
+use std::collections::HashMap;
+
+/// 最多同时请求的地址块数。
+pub const MAX_CONCURRENT_CHUNKS: usize = 4;
+
+/// 按地址归类的批量查询结果，每个输入地址都有一项（没有交易时为空列表）。
+/// 键为调用方传入的地址（去掉首尾空白）；EVM 链上同一地址的不同大小写只保留第一次出现的写法，
+/// 如传入校验和格式的地址，用同样的写法查找即可。
+///
+/// 一笔交易涉及多个输入地址时（如两个输入地址之间的转账），会出现在每个相关地址下。
+pub type ByAddress<T> = HashMap<String, Vec<T>>;
+
+/// 带有发送方与接收方的交易，用于把批量结果归到具体地址。
+pub trait Counterparties {
+    /// 发送方，UTXO 链上有多个时以逗号分隔。
+    fn sender(&self) -> &str;
+
+    /// 接收方，UTXO 链上有多个时以逗号分隔。
+    fn receiver(&self) -> &str;
+
+    /// 交易是否涉及 `address`。`fold_case` 为 `true` 时（EVM 地址）忽略大小写，
+    /// 否则按原样比较（BTC、TRON 等链的 base58 地址区分大小写）。
+    fn involves(&self, address: &str, fold_case: bool) -> bool {
+        let matches = |parties: &str| {
+            parties
+                .split(',')
+                .any(|party| same_address(party.trim(), address, fold_case))
+        };
+        matches(self.sender()) || matches(self.receiver())
+    }
+}
+
+macro_rules! impl_counterparties {
+    ($($ty:ty),*) => {
+        $(
+            impl Counterparties for $ty {
+                fn sender(&self) -> &str {
+                    &self.from
+                }
+
+                fn receiver(&self) -> &str {
+                    &self.to
+                }
+            }
+        )*
+    };
+}
+
+impl_counterparties!(NormalTransaction, InternalTransaction, TokenTransaction);
+
+fn same_address(a: &str, b: &str, fold_case: bool) -> bool {
+    if fold_case {
+        a.eq_ignore_ascii_case(b)
+    } else {
+        a == b
+    }
+}
+
+/// 去重并保持输入顺序。`fold_case` 为 `true` 时（EVM 地址不区分大小写）同一地址的
+/// 不同大小写只查询一次，保留第一次出现的写法。
+fn unique<'a>(addresses: &[&'a str], fold_case: bool) -> Vec<&'a str> {
+    let mut seen: Vec<&str> = Vec::with_capacity(addresses.len());
+    for address in addresses {
+        let address = address.trim();
+        if !address.is_empty() && !seen.iter().any(|s| same_address(s, address, fold_case)) {
+            seen.push(address);
+        }
+    }
+    seen
+}
+
+impl OkLinkClient {
+    /// 与 [`OkLinkClient::query`] 相同，但持有参数，返回的 future 不借用临时值。
+    async fn query_owned<P: EndpointParams>(&self, params: P) -> Result<P::Output> {
+        self.query(&params).await
+    }
+
+    /// 当前链的地址是否不区分大小写（EVM 链）。
+    fn fold_address_case(&self) -> bool {
+        self.chain().is_some_and(|chain| chain.is_evm())
+    }
+
+    /// 遍历一个地址块的全部页面。
+    async fn fetch_chunk<'a, P, L, F>(
+        &self,
+        chunk: &'a [&'a str],
+        make: &F,
+    ) -> Result<(&'a [&'a str], Vec<L::Item>)>
+    where
+        P: EndpointParams<Output = OkLinkResp<L>>,
+        L: Paginated,
+        F: Fn(&[&str], usize) -> P,
+    {
+        let items = paginate(1, |page| self.query_owned(make(chunk, page as usize)))
+            .try_collect()
+            .await?;
+        Ok((chunk, items))
+    }
+
+    /// 分块并发查询，每块遍历全部页面，再把每块的结果归到该块的地址下。
+    ///
+    /// 只在块内归类：两个地址分在不同块时，它们之间的转账会被两块各返回一次，
+    /// 在块内归类可以保证每个地址下不会出现重复的交易。
+    async fn fan_out<P, L, F>(&self, addresses: &[&str], make: F) -> Result<ByAddress<L::Item>>
+    where
+        P: EndpointParams<Output = OkLinkResp<L>>,
+        L: Paginated,
+        L::Item: Counterparties + Clone,
+        F: Fn(&[&str], usize) -> P,
+    {
+        let fold_case = self.fold_address_case();
+        let addresses = unique(addresses, fold_case);
+        let requests: Vec<_> = addresses
+            .chunks(MAX_TRANSACTION_ADDRESSES)
+            .map(|chunk| self.fetch_chunk(chunk, &make))
+            .collect();
+        let chunks: Vec<(&[&str], Vec<L::Item>)> = stream::iter(requests)
+            .buffered(MAX_CONCURRENT_CHUNKS)
+            .try_collect()
+            .await?;
+
+        let mut result: ByAddress<L::Item> = addresses
+            .iter()
+            .map(|address| (address.to_string(), Vec::new()))
+            .collect();
+        for (chunk, items) in chunks {
+            for item in items {
+                for address in chunk
+                    .iter()
+                    .filter(|address| item.involves(address, fold_case))
+                {
+                    if let Some(list) = result.get_mut(*address) {
+                        list.push(item.clone());
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// 批量查询地址的原生代币余额
+    ///
+    /// # 参数
+    /// - `addresses`: 要查询的地址，数量不限，按 [`MAX_BALANCE_ADDRESSES`] 个一组请求。
+    ///
+    /// # 返回值
+    /// 合并后的余额列表，顺序与去重后的输入地址一致。
+    pub async fn get_address_balance_batch(&self, addresses: &[&str]) -> Result<BalanceMulti> {
+        let addresses = unique(addresses, self.fold_address_case());
+        let requests: Vec<_> = addresses
+            .chunks(MAX_BALANCE_ADDRESSES)
+            .map(|chunk| self.query_owned(BalanceMultiParams::new(chunk.join(","))))
+            .collect();
+        let responses: Vec<OkLinkResp<BalanceMulti>> = stream::iter(requests)
+            .buffered(MAX_CONCURRENT_CHUNKS)
+            .try_collect()
+            .await?;
+
+        let mut merged = BalanceMulti {
+            symbol: String::new(),
+            balance_list: Vec::with_capacity(addresses.len()),
+        };
+        for balance in responses.into_iter().flat_map(|resp| resp.data) {
+            if merged.symbol.is_empty() {
+                merged.symbol = balance.symbol;
+            }
+            merged.balance_list.extend(balance.balance_list);
+        }
+        Ok(merged)
+    }
+
+    /// 批量查询地址在区块区间内的交易
+    ///
+    /// # 参数
+    /// - `addresses`: 要查询的地址，数量不限。
+    /// - `start_block_height`: 起始区块高度（含）。
+    /// - `end_block_height`: 结束区块高度（含）。
+    pub async fn get_address_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<NormalTransaction>> {
+        self.fan_out(addresses, |chunk, page| {
+            AddressTransactionListMultiParams::new(chunk, start_block_height, end_block_height)
+                .with_page(page)
+                .with_limit(MAX_LIMIT)
+        })
+        .await
+    }
+
+    /// 批量查询地址在区块区间内的普通交易
+    ///
+    /// # 参数
+    /// - `addresses`: 要查询的地址，数量不限。
+    /// - `start_block_height`: 起始区块高度（含）。
+    /// - `end_block_height`: 结束区块高度（含）。
+    pub async fn get_address_normal_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<NormalTransaction>> {
+        self.fan_out(addresses, |chunk, page| {
+            AddressNormalTransactionListMultiParams::new(
+                chunk,
+                start_block_height,
+                end_block_height,
+            )
+            .with_page(page)
+            .with_limit(MAX_LIMIT)
+        })
+        .await
+    }
+
+    /// 批量查询地址在区块区间内的内部交易
+    ///
+    /// # 参数
+    /// - `addresses`: 要查询的地址，数量不限。
+    /// - `start_block_height`: 起始区块高度（含）。
+    /// - `end_block_height`: 结束区块高度（含）。
+    pub async fn get_address_internal_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<InternalTransaction>> {
+        self.fan_out(addresses, |chunk, page| {
+            AddressInternalTransactionListMultiParams::new(
+                chunk,
+                start_block_height,
+                end_block_height,
+            )
+            .with_page(page)
+            .with_limit(MAX_LIMIT)
+        })
+        .await
+    }
+
+    /// 批量查询地址在区块区间内的代币转账
+    ///
+    /// # 参数
+    /// - `addresses`: 要查询的地址，数量不限。
+    /// - `protocol_type`: 代币类型，如 `ProtocolType::Token20`。
+    /// - `start_block_height`: 起始区块高度（含）。
+    /// - `end_block_height`: 结束区块高度（含）。
+    pub async fn get_address_token_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        protocol_type: ProtocolType,
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<TokenTransaction>> {
+        self.fan_out(addresses, |chunk, page| {
+            AddressTokenTransactionListMultiParams::new(
+                chunk,
+                protocol_type,
+                start_block_height,
+                end_block_height,
+            )
+            .with_page(page)
+            .with_limit(MAX_LIMIT)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{OkLinkTestServer, default_fixture},
+        types::OkApiUri,
+    };
+    use serde_json::json;
+
+    const FROM: &str = "0x85c6627c4ed773cb7c32644b041f58a058b00d30";
+    const TO: &str = "0x28c6c06298d514db089934071355e5743bf21d60";
+    /// `FROM` 的 EIP-55 校验和写法。
+    const FROM_CHECKSUM: &str = "0x85c6627c4Ed773Cb7c32644b041f58A058b00D30";
+
+    fn addresses(n: usize) -> Vec<String> {
+        (0..n).map(|i| format!("0x{:040x}", i)).collect()
+    }
+
+    #[tokio::test]
+    async fn test_transactions_are_chunked_and_attributed() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let client = server.client_builder().chain("ETH").build().unwrap();
+
+        // 45 个地址分为 20 + 20 + 5 三块，转账双方分别在第一块和最后一块
+        let mut owned = addresses(43);
+        owned.insert(0, FROM_CHECKSUM.to_string());
+        owned.push(TO.to_string());
+        let mut input: Vec<&str> = owned.iter().map(String::as_str).collect();
+        input.push(TO);
+
+        let txs = client
+            .get_address_token_transaction_list_batch(
+                &input,
+                ProtocolType::Token20,
+                18809970,
+                18809972,
+            )
+            .await
+            .unwrap();
+
+        let requests = server.requests_to(OkApiUri::AddressTokenTransactionMulti);
+        // 各块并发请求，到达顺序不固定
+        let mut sizes: Vec<_> = requests
+            .iter()
+            .map(|r| r.param("address").unwrap().split(',').count())
+            .collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![5, 20, 20]);
+        assert!(requests.iter().all(|r| r.param("limit") == Some("100")));
+
+        assert_eq!(txs.len(), 45);
+        assert_eq!(txs[FROM_CHECKSUM].len(), 1);
+        assert_eq!(txs[TO].len(), 1);
+        assert_eq!(txs[TO][0].symbol, "USDT");
+        assert!(txs[owned[1].as_str()].is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_evm_addresses_deduplicated_ignoring_case() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let client = server.client_builder().chain("ETH").build().unwrap();
+
+        let input = [FROM_CHECKSUM, FROM, TO];
+        let txs = client
+            .get_address_token_transaction_list_batch(
+                &input,
+                ProtocolType::Token20,
+                18809970,
+                18809972,
+            )
+            .await
+            .unwrap();
+
+        let requests = server.requests_to(OkApiUri::AddressTokenTransactionMulti);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].param("address"),
+            Some(format!("{FROM_CHECKSUM},{TO}").as_str())
+        );
+        // 键保留调用方第一次传入的写法
+        assert_eq!(txs.len(), 2);
+        assert_eq!(txs[FROM_CHECKSUM].len(), 1);
+        assert!(!txs.contains_key(FROM));
+        assert_eq!(txs[TO].len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_base58_addresses_are_case_sensitive() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let client = server.client().unwrap();
+        let sender = "1BM1sAcrfV6d4zPKytzziu4McLQDsFC2Qc";
+        let other = "1bm1sacrfv6d4zpkytzziu4mclqdsfc2qc";
+        let mut fixture = default_fixture(OkApiUri::AddressTransactionMulti);
+        fixture["data"][0]["transactionList"][0]["from"] = json!(sender);
+        server.set_fixture(OkApiUri::AddressTransactionMulti, fixture);
+
+        let txs = client
+            .get_address_transaction_list_batch(&[sender, other], 756073, 756074)
+            .await
+            .unwrap();
+
+        let requests = server.requests_to(OkApiUri::AddressTransactionMulti);
+        assert_eq!(
+            requests[0].param("address"),
+            Some(format!("{sender},{other}").as_str())
+        );
+        assert_eq!(txs[sender].len(), 1);
+        assert!(txs[other].is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_balances_are_merged() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let client = server.client().unwrap();
+
+        let owned = addresses(150);
+        let input: Vec<&str> = owned.iter().map(String::as_str).collect();
+        let balances = client.get_address_balance_batch(&input).await.unwrap();
+
+        let requests = server.requests_to(OkApiUri::BalanceMulti);
+        assert_eq!(requests.len(), 2);
+        let mut sizes: Vec<_> = requests
+            .iter()
+            .map(|r| r.param("address").unwrap().split(',').count())
+            .collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![50, 100]);
+        let fixture = default_fixture(OkApiUri::BalanceMulti);
+        assert_eq!(balances.symbol, fixture["data"][0]["symbol"]);
+        assert_eq!(balances.balance_list.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_chunk_error_fails_the_batch() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let client = server
+            .client_builder()
+            .retry_policy(crate::retry::RetryPolicy::none())
+            .build()
+            .unwrap();
+        server.push_fault(
+            OkApiUri::AddressTransactionMulti,
+            crate::testing::Fault::Status(500),
+        );
+
+        let owned = addresses(3);
+        let input: Vec<&str> = owned.iter().map(String::as_str).collect();
+        assert!(
+            client
+                .get_address_transaction_list_batch(&input, 1, 2)
+                .await
+                .is_err()
+        );
+    }
+}
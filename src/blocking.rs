@@ -0,0 +1,852 @@
+//! 同步（阻塞）版本的 OkLink 客户端。
+//!
+//! [`OkLinkClient`] 与异步客户端的接口一一对应，返回相同的 `types` 与 `resp` 模型
+//! （异步的 `*_stream` 方法对应这里返回 `Iterator` 的 `*_iter` 方法），
+//! 适合不希望引入 tokio 运行时的命令行脚本与批处理任务：
+//!
+//! ```ignore
+//! let client = oklink::OkLinkClient::builder()
+//!     .api_key(api_key)
+//!     .build_blocking()?;
+//! let info = client.get_chain_info()?;
+//! ```
+//!
+//! 内部使用一个单线程 tokio 运行时驱动异步客户端，因此重试、限流、Key 池、缓存与磁带
+//! 等配置与异步客户端完全一致。与 `reqwest::blocking` 一样，不能在异步上下文中调用
+//! （包括创建和销毁客户端），否则会 panic。
+//!
+//! 需要启用 `blocking` 特性。
+
+use crate::{
+    Chain, ChainRegistry, OkLinkError, Result,
+    batch::ByAddress,
+    cache::{Cache, CachePolicy},
+    cassette::Cassette,
+    key_pool::KeyPool,
+    params::{
+        AddressInternalTransactionListParams, AddressNormalTransactionListParams,
+        AddressTokenTransactionListParams, AddressTransactionListParams, Closest, EndpointParams,
+        ProtocolType, PublishTxParams,
+    },
+    rate_limit::RateLimiter,
+    resp::{
+        AddressSummary, BalanceMulti, OkLinkBalance, OkLinkBalanceDetail, OkLinkBalancePage,
+        OkLinkDetailInscription, OkLinkResp, PublishTxInfo, Response,
+    },
+    retry::RetryPolicy,
+    types::{
+        AddressInscription, AddressTransaction, AddressTransactionList, BlockChainInfo, BlockFills,
+        BlockHeader, BlockHeightByTime, BlockList, BlockTransaction, BlockTransactionList,
+        BlockTransactionListMulti, BlockTransactionMulti, BlockchainSummary, InscriptionOk,
+        InternalTransaction, InternalTransactionList, NormalTransaction, NormalTransactionList,
+        TokenTransaction, TokenTransactionList, UtxoList, UtxoOk,
+    },
+};
+use futures::{Stream, StreamExt};
+use serde_json::Value;
+use std::{future::Future, sync::Arc};
+use tokio::runtime::Runtime;
+
+/// 同步的 OkLink 客户端，各方法的含义与 [`crate::OkLinkClient`] 上的同名方法一致。
+///
+/// clone 后共享同一个运行时、连接池、限流器和 Key 池。
+#[derive(Debug, Clone)]
+pub struct OkLinkClient {
+    inner: crate::OkLinkClient,
+    runtime: Arc<Runtime>,
+}
+
+impl OkLinkClient {
+    /// 创建一个新的 `OkLinkClient` 实例，参数与 [`crate::OkLinkClient::new`] 相同。
+    ///
+    /// `api_key` 包含非法字符或无法创建运行时时会 panic，需要校验输入时请使用
+    /// [`crate::OkLinkClientBuilder::build_blocking`]。
+    pub fn new(base_url: String, api_key: String, chain: String, chain_id: u64) -> Self {
+        Self::from_async(crate::OkLinkClient::new(base_url, api_key, chain, chain_id))
+            .expect("failed to build tokio runtime")
+    }
+
+    /// 由已配置好的异步客户端创建同步客户端。
+    pub fn from_async(inner: crate::OkLinkClient) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| OkLinkError::Config(format!("failed to build tokio runtime: {}", e)))?;
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// 返回内部的异步客户端。
+    pub fn as_async(&self) -> &crate::OkLinkClient {
+        &self.inner
+    }
+
+    /// 返回一个指向另一条链的客户端，与当前客户端共享运行时与连接池。
+    pub fn on_chain(&self, chain: Chain) -> OkLinkClient {
+        OkLinkClient {
+            inner: self.inner.on_chain(chain),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// 当前客户端所在的链，链名称无法识别时返回 `None`。
+    pub fn chain(&self) -> Option<Chain> {
+        self.inner.chain()
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.inner = self.inner.with_retry_policy(retry_policy);
+        self
+    }
+
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.inner = self.inner.with_rate_limiter(rate_limiter);
+        self
+    }
+
+    pub fn with_key_pool(mut self, key_pool: KeyPool) -> Self {
+        self.inner = self.inner.with_key_pool(key_pool);
+        self
+    }
+
+    pub fn with_cache<C: Cache + 'static>(mut self, cache: C) -> Self {
+        self.inner = self.inner.with_cache(cache);
+        self
+    }
+
+    pub fn with_cache_policy(mut self, cache_policy: CachePolicy) -> Self {
+        self.inner = self.inner.with_cache_policy(cache_policy);
+        self
+    }
+
+    pub fn with_raw_body(mut self, raw_body: bool) -> Self {
+        self.inner = self.inner.with_raw_body(raw_body);
+        self
+    }
+
+    pub fn with_cassette(mut self, cassette: Cassette) -> Self {
+        self.inner = self.inner.with_cassette(cassette);
+        self
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// 在内部运行时上逐条驱动异步 `Stream`。
+    fn iterate<'a, S>(&'a self, stream: S) -> impl Iterator<Item = S::Item> + 'a
+    where
+        S: Stream + 'a,
+    {
+        let mut stream = Box::pin(stream);
+        std::iter::from_fn(move || self.block_on(stream.next()))
+    }
+
+    /// 按请求参数调用接口，参见 [`crate::OkLinkClient::query`]。
+    pub fn query<P: EndpointParams>(&self, params: &P) -> Result<P::Output> {
+        self.block_on(self.inner.query(params))
+    }
+
+    pub fn query_with_meta<P: EndpointParams>(&self, params: &P) -> Result<Response<P::Output>> {
+        self.block_on(self.inner.query_with_meta(params))
+    }
+
+    pub fn publish(&self, params: &PublishTxParams) -> Result<OkLinkResp<PublishTxInfo>> {
+        self.block_on(self.inner.publish(params))
+    }
+
+    pub fn publish_with_meta(
+        &self,
+        params: &PublishTxParams,
+    ) -> Result<Response<OkLinkResp<PublishTxInfo>>> {
+        self.block_on(self.inner.publish_with_meta(params))
+    }
+
+    pub fn get_token_price_market_data(&self) -> Result<Value> {
+        self.block_on(self.inner.get_token_price_market_data())
+    }
+
+    pub fn get_token_price_market_data_with_meta(&self) -> Result<Response<Value>> {
+        self.block_on(self.inner.get_token_price_market_data_with_meta())
+    }
+
+    pub fn get_address_summary_oklink(&self, address: &str) -> Result<OkLinkResp<AddressSummary>> {
+        self.block_on(self.inner.get_address_summary_oklink(address))
+    }
+
+    pub fn get_address_summary_oklink_with_meta(
+        &self,
+        address: &str,
+    ) -> Result<Response<OkLinkResp<AddressSummary>>> {
+        self.block_on(self.inner.get_address_summary_oklink_with_meta(address))
+    }
+
+    pub fn get_address_balance_oklink_multi(
+        &self,
+        address: &str,
+    ) -> Result<OkLinkResp<BalanceMulti>> {
+        self.block_on(self.inner.get_address_balance_oklink_multi(address))
+    }
+
+    pub fn get_address_balance_oklink_multi_with_meta(
+        &self,
+        address: &str,
+    ) -> Result<Response<OkLinkResp<BalanceMulti>>> {
+        self.block_on(
+            self.inner
+                .get_address_balance_oklink_multi_with_meta(address),
+        )
+    }
+
+    pub fn get_address_balance_batch(&self, addresses: &[&str]) -> Result<BalanceMulti> {
+        self.block_on(self.inner.get_address_balance_batch(addresses))
+    }
+
+    pub fn get_address_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<NormalTransaction>> {
+        self.block_on(self.inner.get_address_transaction_list_batch(
+            addresses,
+            start_block_height,
+            end_block_height,
+        ))
+    }
+
+    pub fn get_address_normal_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<NormalTransaction>> {
+        self.block_on(self.inner.get_address_normal_transaction_list_batch(
+            addresses,
+            start_block_height,
+            end_block_height,
+        ))
+    }
+
+    pub fn get_address_internal_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<InternalTransaction>> {
+        self.block_on(self.inner.get_address_internal_transaction_list_batch(
+            addresses,
+            start_block_height,
+            end_block_height,
+        ))
+    }
+
+    pub fn get_address_token_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        protocol_type: ProtocolType,
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<TokenTransaction>> {
+        self.block_on(self.inner.get_address_token_transaction_list_batch(
+            addresses,
+            protocol_type,
+            start_block_height,
+            end_block_height,
+        ))
+    }
+
+    pub fn get_address_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<AddressTransactionList>> {
+        self.block_on(
+            self.inner
+                .get_address_transaction_list(address, page, limit),
+        )
+    }
+
+    pub fn get_address_transaction_list_with_meta(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<AddressTransactionList>>> {
+        self.block_on(
+            self.inner
+                .get_address_transaction_list_with_meta(address, page, limit),
+        )
+    }
+
+    /// 逐条遍历全部分页数据，参见 [`crate::OkLinkClient::address_transaction_list_stream`]。
+    pub fn address_transaction_list_iter(
+        &self,
+        params: AddressTransactionListParams,
+        prefetch: usize,
+    ) -> impl Iterator<Item = Result<AddressTransaction>> + '_ {
+        self.iterate(self.inner.address_transaction_list_stream(params, prefetch))
+    }
+
+    pub fn get_address_normal_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<NormalTransactionList>> {
+        self.block_on(
+            self.inner
+                .get_address_normal_transaction_list(address, page, limit),
+        )
+    }
+
+    pub fn get_address_normal_transaction_list_with_meta(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<NormalTransactionList>>> {
+        self.block_on(
+            self.inner
+                .get_address_normal_transaction_list_with_meta(address, page, limit),
+        )
+    }
+
+    /// 逐条遍历全部分页数据，参见 [`crate::OkLinkClient::address_normal_transaction_list_stream`]。
+    pub fn address_normal_transaction_list_iter(
+        &self,
+        params: AddressNormalTransactionListParams,
+        prefetch: usize,
+    ) -> impl Iterator<Item = Result<NormalTransaction>> + '_ {
+        self.iterate(
+            self.inner
+                .address_normal_transaction_list_stream(params, prefetch),
+        )
+    }
+
+    pub fn get_address_internal_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<InternalTransactionList>> {
+        self.block_on(
+            self.inner
+                .get_address_internal_transaction_list(address, page, limit),
+        )
+    }
+
+    pub fn get_address_internal_transaction_list_with_meta(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<InternalTransactionList>>> {
+        self.block_on(
+            self.inner
+                .get_address_internal_transaction_list_with_meta(address, page, limit),
+        )
+    }
+
+    /// 逐条遍历全部分页数据，参见 [`crate::OkLinkClient::address_internal_transaction_list_stream`]。
+    pub fn address_internal_transaction_list_iter(
+        &self,
+        params: AddressInternalTransactionListParams,
+        prefetch: usize,
+    ) -> impl Iterator<Item = Result<InternalTransaction>> + '_ {
+        self.iterate(
+            self.inner
+                .address_internal_transaction_list_stream(params, prefetch),
+        )
+    }
+
+    pub fn get_address_token_transaction_list(
+        &self,
+        address: &str,
+        protocol_type: ProtocolType,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<TokenTransactionList>> {
+        self.block_on(self.inner.get_address_token_transaction_list(
+            address,
+            protocol_type,
+            page,
+            limit,
+        ))
+    }
+
+    pub fn get_address_token_transaction_list_with_meta(
+        &self,
+        address: &str,
+        protocol_type: ProtocolType,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<TokenTransactionList>>> {
+        self.block_on(self.inner.get_address_token_transaction_list_with_meta(
+            address,
+            protocol_type,
+            page,
+            limit,
+        ))
+    }
+
+    /// 逐条遍历全部分页数据，参见 [`crate::OkLinkClient::address_token_transaction_list_stream`]。
+    pub fn address_token_transaction_list_iter(
+        &self,
+        params: AddressTokenTransactionListParams,
+        prefetch: usize,
+    ) -> impl Iterator<Item = Result<TokenTransaction>> + '_ {
+        self.iterate(
+            self.inner
+                .address_token_transaction_list_stream(params, prefetch),
+        )
+    }
+
+    pub fn get_brc20_balance_oklink(
+        &self,
+        address: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<OkLinkResp<OkLinkBalancePage>> {
+        self.block_on(
+            self.inner
+                .get_brc20_balance_oklink(address, page, page_size),
+        )
+    }
+
+    pub fn get_brc20_balance_oklink_with_meta(
+        &self,
+        address: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<Response<OkLinkResp<OkLinkBalancePage>>> {
+        self.block_on(
+            self.inner
+                .get_brc20_balance_oklink_with_meta(address, page, page_size),
+        )
+    }
+
+    /// 逐条遍历全部分页数据，参见 [`crate::OkLinkClient::get_brc20_balance_stream`]。
+    pub fn get_brc20_balance_iter<'a>(
+        &'a self,
+        address: &'a str,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Iterator<Item = Result<OkLinkBalance>> + 'a {
+        self.iterate(
+            self.inner
+                .get_brc20_balance_stream(address, limit, prefetch),
+        )
+    }
+
+    pub fn get_brc20_token_detail_oklink(
+        &self,
+        address: &str,
+        tick: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<OkLinkResp<OkLinkBalanceDetail>> {
+        self.block_on(
+            self.inner
+                .get_brc20_token_detail_oklink(address, tick, page, page_size),
+        )
+    }
+
+    pub fn get_brc20_token_detail_oklink_with_meta(
+        &self,
+        address: &str,
+        tick: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<Response<OkLinkResp<OkLinkBalanceDetail>>> {
+        self.block_on(
+            self.inner
+                .get_brc20_token_detail_oklink_with_meta(address, tick, page, page_size),
+        )
+    }
+
+    /// 逐条遍历全部分页数据，参见 [`crate::OkLinkClient::get_brc20_token_detail_stream`]。
+    pub fn get_brc20_token_detail_iter<'a>(
+        &'a self,
+        address: &'a str,
+        tick: &'a str,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Iterator<Item = Result<OkLinkDetailInscription>> + 'a {
+        self.iterate(
+            self.inner
+                .get_brc20_token_detail_stream(address, tick, limit, prefetch),
+        )
+    }
+
+    pub fn address_inscription_list_oklink(
+        &self,
+        address: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<Value> {
+        self.block_on(
+            self.inner
+                .address_inscription_list_oklink(address, page, page_size),
+        )
+    }
+
+    pub fn address_inscription_list_oklink_with_meta(
+        &self,
+        address: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<Response<Value>> {
+        self.block_on(
+            self.inner
+                .address_inscription_list_oklink_with_meta(address, page, page_size),
+        )
+    }
+
+    /// 逐条遍历全部分页数据，参见 [`crate::OkLinkClient::address_inscription_list_stream`]。
+    pub fn address_inscription_list_iter<'a>(
+        &'a self,
+        address: &'a str,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Iterator<Item = Result<AddressInscription>> + 'a {
+        self.iterate(
+            self.inner
+                .address_inscription_list_stream(address, limit, prefetch),
+        )
+    }
+
+    pub fn publish_tx(&self, signed_tx: &str) -> Result<PublishTxInfo> {
+        self.block_on(self.inner.publish_tx(signed_tx))
+    }
+
+    pub fn publish_tx_with_meta(
+        &self,
+        signed_tx: &str,
+    ) -> Result<Response<OkLinkResp<PublishTxInfo>>> {
+        self.block_on(self.inner.publish_tx_with_meta(signed_tx))
+    }
+
+    pub fn get_btc_utxo_oklink(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<UtxoList>> {
+        self.block_on(self.inner.get_btc_utxo_oklink(address, page, limit))
+    }
+
+    pub fn get_btc_utxo_oklink_with_meta(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<UtxoList>>> {
+        self.block_on(
+            self.inner
+                .get_btc_utxo_oklink_with_meta(address, page, limit),
+        )
+    }
+
+    /// 逐条遍历全部分页数据，参见 [`crate::OkLinkClient::get_btc_utxo_stream`]。
+    pub fn get_btc_utxo_iter<'a>(
+        &'a self,
+        address: &'a str,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Iterator<Item = Result<UtxoOk>> + 'a {
+        self.iterate(self.inner.get_btc_utxo_stream(address, limit, prefetch))
+    }
+
+    pub fn get_utxo_by_inscription_id_oklink(
+        &self,
+        inscription_id: &str,
+        inscription_number: &str,
+    ) -> Result<OkLinkResp<InscriptionOk>> {
+        self.block_on(
+            self.inner
+                .get_utxo_by_inscription_id_oklink(inscription_id, inscription_number),
+        )
+    }
+
+    pub fn get_utxo_by_inscription_id_oklink_with_meta(
+        &self,
+        inscription_id: &str,
+        inscription_number: &str,
+    ) -> Result<Response<OkLinkResp<InscriptionOk>>> {
+        self.block_on(
+            self.inner
+                .get_utxo_by_inscription_id_oklink_with_meta(inscription_id, inscription_number),
+        )
+    }
+
+    pub fn get_btc_transaction_list_oklink(
+        &self,
+        height: usize,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<BlockTransactionList>> {
+        self.block_on(
+            self.inner
+                .get_btc_transaction_list_oklink(height, page, limit),
+        )
+    }
+
+    pub fn get_btc_transaction_list_oklink_with_meta(
+        &self,
+        height: usize,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<BlockTransactionList>>> {
+        self.block_on(
+            self.inner
+                .get_btc_transaction_list_oklink_with_meta(height, page, limit),
+        )
+    }
+
+    /// 逐条遍历全部分页数据，参见 [`crate::OkLinkClient::get_btc_transaction_list_stream`]。
+    pub fn get_btc_transaction_list_iter(
+        &self,
+        height: usize,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Iterator<Item = Result<BlockTransaction>> + '_ {
+        self.iterate(
+            self.inner
+                .get_btc_transaction_list_stream(height, limit, prefetch),
+        )
+    }
+
+    pub fn get_btc_transaction_list_multi_oklink(
+        &self,
+        from_block: usize,
+        to_block: usize,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<BlockTransactionListMulti>> {
+        self.block_on(
+            self.inner
+                .get_btc_transaction_list_multi_oklink(from_block, to_block, page, limit),
+        )
+    }
+
+    pub fn get_btc_transaction_list_multi_oklink_with_meta(
+        &self,
+        from_block: usize,
+        to_block: usize,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<BlockTransactionListMulti>>> {
+        self.block_on(
+            self.inner
+                .get_btc_transaction_list_multi_oklink_with_meta(from_block, to_block, page, limit),
+        )
+    }
+
+    /// 逐条遍历全部分页数据，参见 [`crate::OkLinkClient::get_btc_transaction_list_multi_stream`]。
+    pub fn get_btc_transaction_list_multi_iter(
+        &self,
+        from_block: usize,
+        to_block: usize,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Iterator<Item = Result<BlockTransactionMulti>> + '_ {
+        self.iterate(
+            self.inner
+                .get_btc_transaction_list_multi_stream(from_block, to_block, limit, prefetch),
+        )
+    }
+
+    pub fn get_block_fills(&self, height: usize) -> Result<OkLinkResp<BlockFills>> {
+        self.block_on(self.inner.get_block_fills(height))
+    }
+
+    pub fn get_block_fills_with_meta(
+        &self,
+        height: usize,
+    ) -> Result<Response<OkLinkResp<BlockFills>>> {
+        self.block_on(self.inner.get_block_fills_with_meta(height))
+    }
+
+    pub fn get_block_list(&self, page: usize, limit: usize) -> Result<OkLinkResp<BlockList>> {
+        self.block_on(self.inner.get_block_list(page, limit))
+    }
+
+    pub fn get_block_list_with_meta(
+        &self,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<BlockList>>> {
+        self.block_on(self.inner.get_block_list_with_meta(page, limit))
+    }
+
+    /// 逐条遍历全部分页数据，参见 [`crate::OkLinkClient::get_block_list_stream`]。
+    pub fn get_block_list_iter(
+        &self,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Iterator<Item = Result<BlockHeader>> + '_ {
+        self.iterate(self.inner.get_block_list_stream(limit, prefetch))
+    }
+
+    pub fn get_block_height_by_time(
+        &self,
+        time: u64,
+        closest: Closest,
+    ) -> Result<OkLinkResp<BlockHeightByTime>> {
+        self.block_on(self.inner.get_block_height_by_time(time, closest))
+    }
+
+    pub fn get_block_height_by_time_with_meta(
+        &self,
+        time: u64,
+        closest: Closest,
+    ) -> Result<Response<OkLinkResp<BlockHeightByTime>>> {
+        self.block_on(self.inner.get_block_height_by_time_with_meta(time, closest))
+    }
+
+    pub fn block_range_by_time(&self, start_time: u64, end_time: u64) -> Result<(u64, u64)> {
+        self.block_on(self.inner.block_range_by_time(start_time, end_time))
+    }
+
+    pub fn get_chain_info(&self) -> Result<OkLinkResp<BlockChainInfo>> {
+        self.block_on(self.inner.get_chain_info())
+    }
+
+    pub fn get_chain_info_with_meta(&self) -> Result<Response<OkLinkResp<BlockChainInfo>>> {
+        self.block_on(self.inner.get_chain_info_with_meta())
+    }
+
+    pub fn get_blockchain_summary(&self) -> Result<OkLinkResp<BlockchainSummary>> {
+        self.block_on(self.inner.get_blockchain_summary())
+    }
+
+    pub fn get_blockchain_summary_with_meta(
+        &self,
+    ) -> Result<Response<OkLinkResp<BlockchainSummary>>> {
+        self.block_on(self.inner.get_blockchain_summary_with_meta())
+    }
+
+    pub fn chain_registry(&self) -> Result<ChainRegistry> {
+        self.block_on(self.inner.chain_registry())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{Fault, OkLinkTestServer, default_fixture},
+        types::OkApiUri,
+    };
+    use serde_json::json;
+
+    /// 测试服务器运行在独立的多线程运行时上，模拟同步程序访问外部服务。
+    fn start_server() -> (Runtime, OkLinkTestServer) {
+        let runtime = Runtime::new().unwrap();
+        let server = runtime.block_on(OkLinkTestServer::start()).unwrap();
+        (runtime, server)
+    }
+
+    #[test]
+    fn test_blocking_endpoints() {
+        let (_runtime, server) = start_server();
+        let client = server.client_builder().build_blocking().unwrap();
+
+        let info = client.get_chain_info().unwrap();
+        assert_eq!(info.data[0].last_height, 812742);
+        client.get_address_summary_oklink("bc1q").unwrap();
+        client.get_btc_utxo_oklink("bc1q", 1, 50).unwrap();
+        client.publish_tx("0200").unwrap();
+
+        let eth = client.on_chain(Chain::Eth);
+        eth.get_chain_info().unwrap();
+        let request = server.requests_to(OkApiUri::ChainInfo).pop().unwrap();
+        assert_eq!(request.param("chainShortName"), Some("ETH"));
+    }
+
+    #[test]
+    fn test_blocking_retries_and_errors() {
+        let (_runtime, server) = start_server();
+        let client = server
+            .client_builder()
+            .retry_policy(
+                RetryPolicy::default().with_base_delay(std::time::Duration::from_millis(1)),
+            )
+            .build_blocking()
+            .unwrap();
+
+        server.push_fault(OkApiUri::ChainInfo, Fault::Status(503));
+        client.get_chain_info().unwrap();
+        assert_eq!(server.requests_to(OkApiUri::ChainInfo).len(), 2);
+
+        server.push_fault(
+            OkApiUri::AddressSummary,
+            Fault::Api {
+                code: "50038".to_string(),
+                msg: "chain not supported".to_string(),
+            },
+        );
+        let err = client.get_address_summary_oklink("bc1q").err().unwrap();
+        assert_eq!(err.code(), Some("50038"));
+    }
+
+    #[test]
+    fn test_blocking_iterators() {
+        let (_runtime, server) = start_server();
+        let client = server.client_builder().build_blocking().unwrap();
+        for page in 1..=2u32 {
+            let mut body = default_fixture(OkApiUri::AddressUtxo);
+            body["data"][0]["page"] = json!(page.to_string());
+            body["data"][0]["totalPage"] = json!("2");
+            body["data"][0]["utxoList"][0]["txid"] = json!(format!("tx{}", page));
+            server.set_page(OkApiUri::AddressUtxo, page, body);
+        }
+
+        let txids: Vec<_> = client
+            .get_btc_utxo_iter("bc1q", 1, 2)
+            .map(|utxo| utxo.map(|u| u.txid))
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(txids, vec!["tx1", "tx2"]);
+
+        // 请求失败时错误作为迭代项返回，之后迭代结束。
+        server.push_fault(OkApiUri::AddressUtxo, Fault::Status(400));
+        let mut utxos = client.get_btc_utxo_iter("bc1q", 1, 1);
+        assert!(utxos.next().unwrap().is_err());
+        assert!(utxos.next().is_none());
+
+        for page in 1..=2u64 {
+            let mut body = default_fixture(OkApiUri::BlockList);
+            body["data"][0]["page"] = json!(page.to_string());
+            body["data"][0]["totalPage"] = json!("2");
+            body["data"][0]["blockList"][0]["height"] = json!((812743 - page).to_string());
+            server.set_page(OkApiUri::BlockList, page as u32, body);
+        }
+        let heights: Vec<_> = client
+            .get_block_list_iter(1, 2)
+            .map(|block| block.map(|b| b.height))
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(heights, vec![812742, 812741]);
+
+        let eth = client.on_chain(Chain::Eth);
+        for page in 1..=2u32 {
+            let mut body = default_fixture(OkApiUri::AddressTransaction);
+            body["data"][0]["page"] = json!(page.to_string());
+            body["data"][0]["totalPage"] = json!("2");
+            body["data"][0]["transactionLists"][0]["txId"] = json!(format!("0x{}", page));
+            server.set_page(OkApiUri::AddressTransaction, page, body);
+        }
+        let params = AddressTransactionListParams::new("0xabc").with_limit(1);
+        let txids: Vec<_> = eth
+            .address_transaction_list_iter(params, 2)
+            .map(|tx| tx.map(|t| t.tx_id))
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(txids, vec!["0x1", "0x2"]);
+    }
+}
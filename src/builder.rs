@@ -0,0 +1,436 @@
+use crate::{
+    OkLinkClient,
+    cache::{Cache, CachePolicy},
+    cassette::Cassette,
+    chain::Chain,
+    error::{OkLinkError, Result},
+    http_client::{ACCESS_KEY_HEADER, HttpClient},
+    key_pool::KeyPool,
+    rate_limit::RateLimiter,
+    retry::RetryPolicy,
+};
+use reqwest::{
+    Client, Proxy, Url,
+    header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT},
+};
+use std::{sync::Arc, time::Duration};
+
+/// OkLink 公共浏览器 v5 接口的默认地址。
+pub const DEFAULT_BASE_URL: &str = "https://www.oklink.com/api/v5/explorer";
+
+/// `OkLinkClientBuilder` 用于构建 `OkLinkClient`。
+///
+/// 所有输入都在 `build` 时校验，非法配置返回 `OkLinkError::Config` 而不是 panic。
+#[derive(Debug, Default)]
+pub struct OkLinkClientBuilder {
+    base_url: Option<String>,
+    api_key: Option<String>,
+    key_pool: Option<KeyPool>,
+    chain: Option<String>,
+    chain_id: Option<u64>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<String>,
+    user_agent: Option<String>,
+    headers: Vec<(String, String)>,
+    client: Option<Client>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    requests_per_second: Option<u32>,
+    requests_per_day: Option<u64>,
+    cassette: Option<Cassette>,
+    cache: Option<Arc<dyn Cache>>,
+    cache_policy: Option<CachePolicy>,
+    raw_body: bool,
+    #[cfg(feature = "tower")]
+    service: Option<crate::middleware::BoxService>,
+}
+
+impl OkLinkClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// API 基础地址，默认为 [`DEFAULT_BASE_URL`]。
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// 使用 Key 池代替单个 `api_key`，两者不能同时设置。
+    pub fn key_pool(mut self, key_pool: KeyPool) -> Self {
+        self.key_pool = Some(key_pool);
+        self
+    }
+
+    /// 链名称（如 "BTC" 或 `Chain::Btc`），默认为 "btc"。
+    ///
+    /// 未单独设置 `chain_id` 时，会根据已知的链自动推断链 ID。
+    pub fn chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain = Some(chain.into());
+        self
+    }
+
+    /// 链 ID，默认根据 `chain` 推断，无法推断时为 0。
+    pub fn chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// 整个请求的超时时间。
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 建立连接的超时时间。
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// 代理地址，如 `http://127.0.0.1:7890` 或 `socks5://127.0.0.1:1080`。
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// 额外的请求头，会附加到每个请求上。
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// 注入自定义的 `reqwest::Client`。
+    ///
+    /// 注入后不能再设置 `timeout`、`connect_timeout` 和 `proxy`，这些配置应在注入的客户端上完成。
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// 使用 `tower` service 作为传输层，详见 [`crate::middleware`]。
+    ///
+    /// 与 `client` 一样不能再设置 `timeout`、`connect_timeout` 和 `proxy`。
+    /// 设置 service 后客户端自身默认不再重试，重试交给 service 中的
+    /// [`RetryLayer`](crate::middleware::RetryLayer)，避免重复重试；
+    /// service 不包含重试时可以显式设置 `retry_policy`。需要启用 `tower` 特性。
+    #[cfg(feature = "tower")]
+    pub fn service<S>(mut self, service: S) -> Self
+    where
+        S: tower::Service<
+                crate::middleware::HttpRequest,
+                Response = crate::middleware::HttpResponse,
+            > + Clone
+            + Send
+            + Sync
+            + 'static,
+        S::Error: Into<crate::middleware::BoxError>,
+        S::Future: Send + 'static,
+    {
+        self.service = Some(crate::middleware::boxed(service));
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// 自定义限流器，多个客户端可以共享同一个限流器。
+    ///
+    /// 不能与 `requests_per_second`、`requests_per_day` 同时设置。
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// 每秒最多发送的请求数，默认不限制。
+    pub fn requests_per_second(mut self, requests_per_second: u32) -> Self {
+        self.requests_per_second = Some(requests_per_second);
+        self
+    }
+
+    /// 每个 UTC 日最多发送的请求数（API Key 的每日额度），默认不限制。
+    pub fn requests_per_day(mut self, requests_per_day: u64) -> Self {
+        self.requests_per_day = Some(requests_per_day);
+        self
+    }
+
+    /// 挂载录制/回放磁带，详见 [`Cassette`]。
+    pub fn cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// 启用响应缓存，如 [`LruCache`](crate::cache::LruCache)。
+    pub fn cache<C: Cache + 'static>(mut self, cache: C) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// 缓存策略，默认为 `CachePolicy::default()`。
+    pub fn cache_policy(mut self, cache_policy: CachePolicy) -> Self {
+        self.cache_policy = Some(cache_policy);
+        self
+    }
+
+    /// 在 `*_with_meta` 方法返回的元数据中保留原始响应体，默认不保留。
+    pub fn raw_body(mut self, raw_body: bool) -> Self {
+        self.raw_body = raw_body;
+        self
+    }
+
+    /// 校验配置并构建 `OkLinkClient`。
+    pub fn build(self) -> Result<OkLinkClient> {
+        let base_url = self
+            .base_url
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let parsed = Url::parse(&base_url)
+            .map_err(|e| OkLinkError::Config(format!("invalid base url {}: {}", base_url, e)))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(OkLinkError::Config(format!(
+                "base url must be http or https: {}",
+                base_url
+            )));
+        }
+
+        let chain = self.chain.unwrap_or_else(|| "btc".to_string());
+        if chain.trim().is_empty() {
+            return Err(OkLinkError::Config("chain cannot be empty".to_string()));
+        }
+
+        let mut headers = HeaderMap::new();
+        match (&self.api_key, &self.key_pool) {
+            (Some(_), Some(_)) => {
+                return Err(OkLinkError::Config(
+                    "api_key and key_pool are mutually exclusive".to_string(),
+                ));
+            }
+            (Some(api_key), None) if api_key.trim().is_empty() => {
+                return Err(OkLinkError::Config("api key cannot be empty".to_string()));
+            }
+            (Some(api_key), None) => {
+                let value = HeaderValue::from_str(api_key).map_err(|_| {
+                    OkLinkError::Config("api key contains invalid characters".to_string())
+                })?;
+                headers.insert(ACCESS_KEY_HEADER, value);
+            }
+            (None, Some(_)) => {}
+            (None, None) => {
+                return Err(OkLinkError::Config(
+                    "either api_key or key_pool must be set".to_string(),
+                ));
+            }
+        }
+        if let Some(user_agent) = &self.user_agent {
+            let value = HeaderValue::from_str(user_agent)
+                .map_err(|_| OkLinkError::Config(format!("invalid user agent: {}", user_agent)))?;
+            headers.insert(USER_AGENT, value);
+        }
+        for (name, value) in &self.headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| OkLinkError::Config(format!("invalid header name: {}", name)))?;
+            let header_value = HeaderValue::from_str(value)
+                .map_err(|_| OkLinkError::Config(format!("invalid value for header {}", name)))?;
+            headers.insert(header_name, header_value);
+        }
+
+        if self.requests_per_second == Some(0) || self.requests_per_day == Some(0) {
+            return Err(OkLinkError::Config(
+                "requests_per_second and requests_per_day must be greater than 0".to_string(),
+            ));
+        }
+        let rate_limiter = match (
+            self.rate_limiter,
+            self.requests_per_second,
+            self.requests_per_day,
+        ) {
+            (Some(_), Some(_), _) | (Some(_), _, Some(_)) => {
+                return Err(OkLinkError::Config(
+                    "requests_per_second and requests_per_day cannot be combined with rate_limiter"
+                        .to_string(),
+                ));
+            }
+            (Some(rate_limiter), None, None) => Some(rate_limiter),
+            (None, None, None) => None,
+            (None, per_second, per_day) => Some(RateLimiter::new(per_second, per_day)),
+        };
+
+        #[cfg(feature = "tower")]
+        let injected = self.client.is_some() || self.service.is_some();
+        #[cfg(not(feature = "tower"))]
+        let injected = self.client.is_some();
+        if injected
+            && (self.timeout.is_some() || self.connect_timeout.is_some() || self.proxy.is_some())
+        {
+            return Err(OkLinkError::Config(
+                "timeout, connect_timeout and proxy cannot be combined with an injected client"
+                    .to_string(),
+            ));
+        }
+
+        // 注入 service 时重试由 service 中的 RetryLayer 负责，除非显式设置了重试策略
+        #[cfg(feature = "tower")]
+        let retry_policy = self
+            .retry_policy
+            .or_else(|| self.service.is_some().then(RetryPolicy::none));
+        #[cfg(not(feature = "tower"))]
+        let retry_policy = self.retry_policy;
+
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder();
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(connect_timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+                if let Some(proxy) = &self.proxy {
+                    let proxy = Proxy::all(proxy).map_err(|e| {
+                        OkLinkError::Config(format!("invalid proxy {}: {}", proxy, e))
+                    })?;
+                    builder = builder.proxy(proxy);
+                }
+                builder.build().map_err(|e| {
+                    OkLinkError::Config(format!("failed to build http client: {}", e))
+                })?
+            }
+        };
+
+        let mut http_client =
+            HttpClient::new(base_url.trim_end_matches('/').to_string(), Some(headers))
+                .with_client(client)
+                .with_raw_body(self.raw_body);
+        #[cfg(feature = "tower")]
+        if let Some(service) = self.service {
+            http_client = http_client.with_boxed_service(service);
+        }
+        if let Some(retry_policy) = retry_policy {
+            http_client = http_client.with_retry_policy(retry_policy);
+        }
+        if let Some(rate_limiter) = rate_limiter {
+            http_client = http_client.with_rate_limiter(rate_limiter);
+        }
+        if let Some(key_pool) = self.key_pool {
+            http_client = http_client.with_key_pool(key_pool);
+        }
+        if let Some(cassette) = self.cassette {
+            http_client = http_client.with_cassette(cassette);
+        }
+        if let Some(cache) = self.cache {
+            http_client = http_client.with_shared_cache(cache);
+        }
+        if let Some(cache_policy) = self.cache_policy {
+            http_client = http_client.with_cache_policy(cache_policy);
+        }
+
+        Ok(OkLinkClient {
+            client: http_client,
+            chain_id: self
+                .chain_id
+                .or_else(|| chain.parse::<Chain>().ok().map(|c| c.chain_id()))
+                .unwrap_or(0),
+            chain,
+        })
+    }
+
+    /// 校验配置并构建同步的 [`blocking::OkLinkClient`](crate::blocking::OkLinkClient)。
+    ///
+    /// 需要启用 `blocking` 特性。
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<crate::blocking::OkLinkClient> {
+        crate::blocking::OkLinkClient::from_async(self.build()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_defaults() {
+        let client = OkLinkClient::builder().api_key("test-key").build().unwrap();
+        assert_eq!(client.chain, "btc");
+        assert_eq!(client.chain_id, 0);
+
+        let client = OkLinkClient::builder()
+            .api_key("test-key")
+            .chain(Chain::Bsc)
+            .build()
+            .unwrap();
+        assert_eq!(client.chain(), Some(Chain::Bsc));
+        assert_eq!(client.chain_id, 56);
+        assert_eq!(client.on_chain(Chain::Eth).chain_id, 1);
+    }
+
+    #[test]
+    fn test_build_rejects_bad_input() {
+        let bad_key = OkLinkClient::builder().api_key("bad\nkey").build();
+        assert!(matches!(bad_key, Err(OkLinkError::Config(_))));
+
+        let no_key = OkLinkClient::builder().build();
+        assert!(matches!(no_key, Err(OkLinkError::Config(_))));
+
+        for blank in ["", "   "] {
+            let blank_key = OkLinkClient::builder().api_key(blank).build();
+            assert!(matches!(blank_key, Err(OkLinkError::Config(_))));
+        }
+
+        let both_keys = OkLinkClient::builder()
+            .api_key("test-key")
+            .key_pool(KeyPool::new(["pool-key"]).unwrap())
+            .build();
+        assert!(matches!(both_keys, Err(OkLinkError::Config(_))));
+
+        let bad_url = OkLinkClient::builder()
+            .api_key("test-key")
+            .base_url("not a url")
+            .build();
+        assert!(matches!(bad_url, Err(OkLinkError::Config(_))));
+
+        let conflicting = OkLinkClient::builder()
+            .api_key("test-key")
+            .client(Client::new())
+            .timeout(Duration::from_secs(5))
+            .build();
+        assert!(matches!(conflicting, Err(OkLinkError::Config(_))));
+
+        let zero_quota = OkLinkClient::builder()
+            .api_key("test-key")
+            .requests_per_day(0)
+            .build();
+        assert!(matches!(zero_quota, Err(OkLinkError::Config(_))));
+
+        let conflicting = OkLinkClient::builder()
+            .api_key("test-key")
+            .rate_limiter(RateLimiter::per_second(5))
+            .requests_per_second(5)
+            .build();
+        assert!(matches!(conflicting, Err(OkLinkError::Config(_))));
+    }
+
+    #[tokio::test]
+    async fn test_build_rate_limits() {
+        let client = OkLinkClient::builder()
+            .api_key("test-key")
+            .requests_per_second(5)
+            .requests_per_day(10_000)
+            .build()
+            .unwrap();
+        let limiter = client.rate_limiter().unwrap();
+        assert_eq!(limiter.remaining_today().await, Some(10_000));
+    }
+}
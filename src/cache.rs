@@ -0,0 +1,490 @@
+//! 响应缓存。
+//!
+//! `HttpClient` 在发送 GET 请求前先查询 [`Cache`]，命中时不消耗 API 额度也不占用限流令牌。
+//! 每个接口的缓存时间由 [`CachePolicy`] 决定：依赖链上最新高度的接口只缓存很短的时间，
+//! 而超过 N 个确认的区块详情与区块交易列表永远不会再变化，会被永久缓存。
+
+use crate::types::OkApiUri;
+use reqwest::Url;
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Debug,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::Instant;
+
+#[cfg(feature = "sqlite")]
+mod sqlite;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteCache;
+
+/// 默认视为不可变所需的确认数。
+pub const DEFAULT_CONFIRMATIONS: u64 = 6;
+
+/// 缓存条目的过期方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expiry {
+    /// 永不过期，用于已确认的历史数据。
+    Never,
+    /// 在指定时间后过期。
+    After(Duration),
+}
+
+/// 响应缓存的存储后端。
+///
+/// 键为请求的路径与查询参数（已包含链名称），值为通过校验的原始 JSON 响应。
+/// 实现需要自行处理过期，过期的条目应当从 `get` 返回 `None`。
+/// 客户端在异步任务中直接调用这些方法，实现不应长时间阻塞。
+pub trait Cache: Send + Sync + Debug {
+    /// 读取未过期的缓存。
+    fn get(&self, key: &str) -> Option<Vec<u8>>;
+
+    /// 写入缓存。
+    fn put(&self, key: &str, value: Vec<u8>, expiry: Expiry);
+
+    /// 删除一个缓存条目。
+    fn invalidate(&self, key: &str);
+
+    /// 清空所有缓存。
+    fn clear(&self);
+}
+
+#[derive(Debug)]
+struct LruEntry {
+    value: Vec<u8>,
+    expires_at: Option<Instant>,
+    tick: u64,
+}
+
+#[derive(Debug, Default)]
+struct LruState {
+    entries: HashMap<String, LruEntry>,
+    order: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl LruState {
+    fn touch(&mut self, key: &str) {
+        self.tick += 1;
+        let tick = self.tick;
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.order.remove(&entry.tick);
+            entry.tick = tick;
+            self.order.insert(tick, key.to_string());
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.order.remove(&entry.tick);
+        }
+    }
+}
+
+/// 基于内存的 LRU 缓存，超过容量时淘汰最久未使用的条目。
+#[derive(Debug, Clone)]
+pub struct LruCache {
+    capacity: usize,
+    state: Arc<Mutex<LruState>>,
+}
+
+impl LruCache {
+    /// 创建最多保存 `capacity` 个响应的缓存。
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: Arc::new(Mutex::new(LruState::default())),
+        }
+    }
+
+    /// 当前缓存的条目数（可能包含尚未清理的过期条目）。
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Cache for LruCache {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let mut state = self.state.lock().unwrap();
+        let expired = state
+            .entries
+            .get(key)?
+            .expires_at
+            .is_some_and(|at| at <= Instant::now());
+        if expired {
+            state.remove(key);
+            return None;
+        }
+        state.touch(key);
+        state.entries.get(key).map(|e| e.value.clone())
+    }
+
+    fn put(&self, key: &str, value: Vec<u8>, expiry: Expiry) {
+        let mut state = self.state.lock().unwrap();
+        state.remove(key);
+        while state.entries.len() >= self.capacity {
+            let Some((_, oldest)) = state.order.pop_first() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+        }
+
+        let expires_at = match expiry {
+            Expiry::Never => None,
+            Expiry::After(ttl) => Some(Instant::now() + ttl),
+        };
+        state.tick += 1;
+        let tick = state.tick;
+        state.order.insert(tick, key.to_string());
+        state.entries.insert(
+            key.to_string(),
+            LruEntry {
+                value,
+                expires_at,
+                tick,
+            },
+        );
+    }
+
+    fn invalidate(&self, key: &str) {
+        self.state.lock().unwrap().remove(key);
+    }
+
+    fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.entries.clear();
+        state.order.clear();
+    }
+}
+
+/// 各接口的缓存策略。
+///
+/// `get_chain_info` 返回的最新高度会被记录下来（也可以通过 `set_tip_height` 手动设置），
+/// 区块详情、区块交易列表（以及指定了 `endBlockHeight` 的地址交易列表）的高度距离最新高度达到
+/// `confirmations` 个确认后永久缓存，否则使用接口的 TTL；按时间查询区块高度时以返回的区块高度判断。
+/// 其他不会再变化的查询（如回填时按铭文 ID 查询的铭文交易）可以通过 `with_immutable` 永久缓存。
+/// clone 出的策略共享同一份最新高度。
+#[derive(Debug, Clone)]
+pub struct CachePolicy {
+    ttls: HashMap<OkApiUri, Option<Duration>>,
+    immutable: HashSet<OkApiUri>,
+    confirmations: u64,
+    tip_heights: Arc<Mutex<HashMap<String, u64>>>,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        let ttls = OkApiUri::ALL
+            .iter()
+            .map(|uri| (*uri, default_ttl(*uri)))
+            .collect();
+        Self {
+            ttls,
+            immutable: HashSet::new(),
+            confirmations: DEFAULT_CONFIRMATIONS,
+            tip_heights: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+/// 接口默认的缓存时间，`None` 表示不缓存。
+fn default_ttl(uri: OkApiUri) -> Option<Duration> {
+    match uri {
+        OkApiUri::ChainInfo | OkApiUri::TokenPriceMarketData | OkApiUri::BlockList => {
+            Some(Duration::from_secs(10))
+        }
+        OkApiUri::AddressSummary
+        | OkApiUri::BalanceMulti
+        | OkApiUri::BtcAddressBalanceList
+        | OkApiUri::BtcAddressBalanceDetail
+        | OkApiUri::InscriptionAddressInscriptionList
+        | OkApiUri::AddressUtxo
+        | OkApiUri::BlockTransaction
+        | OkApiUri::BlockTransactionMulti
+        | OkApiUri::BlockFills
+        | OkApiUri::AddressTransaction
+        | OkApiUri::AddressNormalTransaction
+        | OkApiUri::AddressInternalTransaction
+        | OkApiUri::AddressTokenTransaction
+        | OkApiUri::AddressTransactionMulti
+        | OkApiUri::AddressNormalTransactionMulti
+        | OkApiUri::AddressInternalTransactionMulti
+        | OkApiUri::AddressTokenTransactionMulti => Some(Duration::from_secs(30)),
+        OkApiUri::BrcTransactionList | OkApiUri::BlockHeightByTime => Some(Duration::from_secs(60)),
+        OkApiUri::BlockchainSummary => Some(Duration::from_secs(300)),
+        OkApiUri::TransactionPublicshTx => None,
+    }
+}
+
+impl CachePolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置某个接口的缓存时间。
+    pub fn with_ttl(mut self, uri: OkApiUri, ttl: Duration) -> Self {
+        self.ttls.insert(uri, Some(ttl));
+        self
+    }
+
+    /// 不缓存某个接口。
+    pub fn with_no_cache(mut self, uri: OkApiUri) -> Self {
+        self.ttls.insert(uri, None);
+        self.immutable.remove(&uri);
+        self
+    }
+
+    /// 将某个接口的响应视为不可变并永久缓存，适合只查询历史数据的回填任务，
+    /// 如 [`OkApiUri::BrcTransactionList`]。
+    ///
+    /// 接口默认不缓存（如 `publish_tx`）或设置了 `with_no_cache` 时无效。
+    pub fn with_immutable(mut self, uri: OkApiUri) -> Self {
+        self.immutable.insert(uri);
+        self
+    }
+
+    /// 数据被视为不可变所需的确认数，默认为 [`DEFAULT_CONFIRMATIONS`]。
+    pub fn with_confirmations(mut self, confirmations: u64) -> Self {
+        self.confirmations = confirmations.max(1);
+        self
+    }
+
+    /// 手动设置某条链的最新高度。
+    pub fn set_tip_height(&self, chain: &str, height: u64) {
+        let mut tips = self.tip_heights.lock().unwrap();
+        let tip = tips.entry(chain.to_ascii_lowercase()).or_default();
+        *tip = (*tip).max(height);
+    }
+
+    /// 已知的某条链的最新高度。
+    pub fn tip_height(&self, chain: &str) -> Option<u64> {
+        self.tip_heights
+            .lock()
+            .unwrap()
+            .get(&chain.to_ascii_lowercase())
+            .copied()
+    }
+
+    /// 根据请求与响应计算缓存方式，`None` 表示不缓存。
+    pub(crate) fn expiry_for(&self, uri: &str, response: &Value) -> Option<Expiry> {
+        let (api, query) = parse_uri(uri)?;
+        let ttl = self.ttls.get(&api).copied().flatten()?;
+        if ttl.is_zero() {
+            return None;
+        }
+        if self.immutable.contains(&api) {
+            return Some(Expiry::Never);
+        }
+
+        let height = match api {
+            OkApiUri::BlockTransaction | OkApiUri::BlockFills => query.get("height").cloned(),
+            OkApiUri::BlockTransactionMulti
+            | OkApiUri::AddressTransaction
+            | OkApiUri::AddressNormalTransaction
+            | OkApiUri::AddressInternalTransaction
+            | OkApiUri::AddressTokenTransaction
+            | OkApiUri::AddressTransactionMulti
+            | OkApiUri::AddressNormalTransactionMulti
+            | OkApiUri::AddressInternalTransactionMulti
+            | OkApiUri::AddressTokenTransactionMulti => query.get("endBlockHeight").cloned(),
+            // 过去某个时间附近的区块一旦被足够确认，查询结果就不会再变化
+            OkApiUri::BlockHeightByTime => Some(json_string(&response["data"][0]["height"])),
+            _ => None,
+        }
+        .and_then(|h| h.parse::<u64>().ok());
+        let tip = query
+            .get("chainShortName")
+            .and_then(|chain| self.tip_height(chain));
+        match (height, tip) {
+            (Some(height), Some(tip))
+                if height <= tip && tip - height + 1 >= self.confirmations =>
+            {
+                Some(Expiry::Never)
+            }
+            _ => Some(Expiry::After(ttl)),
+        }
+    }
+
+    /// 从响应中记录链的最新高度。
+    pub(crate) fn observe(&self, uri: &str, response: &Value) {
+        let Some((OkApiUri::ChainInfo, query)) = parse_uri(uri) else {
+            return;
+        };
+        let Some(chain) = query.get("chainShortName") else {
+            return;
+        };
+        if let Ok(height) = json_string(&response["data"][0]["lastHeight"]).parse::<u64>() {
+            self.set_tip_height(chain, height);
+        }
+    }
+}
+
+/// OkLink 的数字字段可能是字符串也可能是数字。
+fn json_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+pub(crate) fn parse_uri(uri: &str) -> Option<(OkApiUri, HashMap<String, String>)> {
+    let api = OkApiUri::from_path(uri)?;
+    let url = Url::parse(&format!("http://localhost{}", uri)).ok()?;
+    Some((api, url.query_pairs().into_owned().collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::OkLinkTestServer;
+    use serde_json::json;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_lru_evicts_and_expires() {
+        let cache = LruCache::new(2);
+        cache.put("a", b"1".to_vec(), Expiry::Never);
+        cache.put("b", b"2".to_vec(), Expiry::After(Duration::from_secs(5)));
+        assert_eq!(cache.get("a"), Some(b"1".to_vec()));
+
+        // "b" 最久未使用，被淘汰。
+        cache.put("c", b"3".to_vec(), Expiry::After(Duration::from_secs(5)));
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.len(), 2);
+
+        tokio::time::advance(Duration::from_secs(6)).await;
+        assert_eq!(cache.get("c"), None);
+        assert_eq!(cache.get("a"), Some(b"1".to_vec()));
+
+        cache.invalidate("a");
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_confirmed_blocks_never_expire() {
+        let policy = CachePolicy::new().with_confirmations(6);
+        let uri = |height: u64| {
+            format!(
+                "{}?chainShortName=btc&height={}&limit=20&page=1",
+                OkApiUri::BlockTransaction.as_str(),
+                height
+            )
+        };
+        let short = Some(Expiry::After(Duration::from_secs(30)));
+
+        assert_eq!(policy.expiry_for(&uri(800000), &Value::Null), short);
+        policy.set_tip_height("BTC", 800005);
+        assert_eq!(
+            policy.expiry_for(&uri(800000), &Value::Null),
+            Some(Expiry::Never)
+        );
+        assert_eq!(policy.expiry_for(&uri(800001), &Value::Null), short);
+
+        // 地址交易列表只有指定了结束高度才会永久缓存
+        let history = "/address/transaction-list?chainShortName=btc&address=bc1q";
+        assert_eq!(policy.expiry_for(history, &Value::Null), short);
+        assert_eq!(
+            policy.expiry_for(&format!("{}&endBlockHeight=800000", history), &Value::Null),
+            Some(Expiry::Never)
+        );
+
+        // 按时间查询区块高度：返回的区块足够确认后永久缓存
+        let by_time = "/block/block-height-by-time?chainShortName=btc&time=1697623175000";
+        let found = |height: &str| json!({ "data": [{ "height": height, "blockTime": "1" }] });
+        assert_eq!(
+            policy.expiry_for(by_time, &found("800000")),
+            Some(Expiry::Never)
+        );
+        assert_eq!(
+            policy.expiry_for(by_time, &found("800003")),
+            Some(Expiry::After(Duration::from_secs(60)))
+        );
+
+        let inscription = "/btc/transaction-list?inscriptionId=abc123i0&inscriptionNumber=42";
+        assert_eq!(
+            policy.expiry_for(inscription, &Value::Null),
+            Some(Expiry::After(Duration::from_secs(60)))
+        );
+        let policy = policy.with_immutable(OkApiUri::BrcTransactionList);
+        assert_eq!(
+            policy.expiry_for(inscription, &Value::Null),
+            Some(Expiry::Never)
+        );
+
+        let policy = policy.with_no_cache(OkApiUri::ChainInfo);
+        assert_eq!(
+            policy.expiry_for("/blockchain/info?chainShortName=btc", &Value::Null),
+            None
+        );
+        assert_eq!(
+            policy.expiry_for(OkApiUri::TransactionPublicshTx.as_str(), &Value::Null),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_serves_from_cache() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let policy = CachePolicy::new();
+        let client = server
+            .client_builder()
+            .cache(LruCache::new(100))
+            .cache_policy(policy.clone())
+            .build()
+            .unwrap();
+
+        client.get_chain_info().await.unwrap();
+        client.get_chain_info().await.unwrap();
+        assert_eq!(server.requests_to(OkApiUri::ChainInfo).len(), 1);
+        // 链信息中的最新高度为 812742。
+        assert_eq!(policy.tip_height("btc"), Some(812742));
+
+        client
+            .get_btc_transaction_list_oklink(800000, 1, 20)
+            .await
+            .unwrap();
+        client
+            .get_btc_transaction_list_oklink(800000, 1, 20)
+            .await
+            .unwrap();
+        client
+            .get_btc_transaction_list_oklink(800000, 2, 20)
+            .await
+            .unwrap();
+        assert_eq!(server.requests_to(OkApiUri::BlockTransaction).len(), 2);
+
+        client.publish_tx("0200").await.unwrap();
+        client.publish_tx("0200").await.unwrap();
+        assert_eq!(server.requests_to(OkApiUri::TransactionPublicshTx).len(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_confirmed_block_fills_never_expire() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let client = server
+            .client_builder()
+            .cache(LruCache::new(100))
+            .build()
+            .unwrap();
+        // 链信息中的最新高度为 812742。
+        client.get_chain_info().await.unwrap();
+
+        client.get_block_fills(812700).await.unwrap();
+        client.get_block_fills(812742).await.unwrap();
+        tokio::time::advance(Duration::from_secs(3600)).await;
+        client.get_block_fills(812700).await.unwrap();
+        client.get_block_fills(812742).await.unwrap();
+
+        let heights: Vec<_> = server
+            .requests_to(OkApiUri::BlockFills)
+            .iter()
+            .map(|r| r.param("height").unwrap().to_string())
+            .collect();
+        assert_eq!(heights, ["812700", "812742", "812742"]);
+    }
+}
@@ -0,0 +1,367 @@
+use super::{Cache, Expiry, parse_uri};
+use crate::{
+    error::{OkLinkError, Result},
+    types::OkApiUri,
+};
+use rusqlite::{Connection, OptionalExtension, params};
+use std::{
+    fmt,
+    path::Path,
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS responses (
+    key TEXT PRIMARY KEY,
+    chain TEXT NOT NULL,
+    endpoint TEXT NOT NULL,
+    value BLOB NOT NULL,
+    size INTEGER NOT NULL,
+    expires_at INTEGER,
+    -- 单调递增的访问序号，用于 LRU 淘汰
+    last_used INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS responses_chain ON responses (chain);
+CREATE INDEX IF NOT EXISTS responses_last_used ON responses (last_used);
+-- 缓存内容的总字节数，由触发器在同一事务中维护，淘汰时不需要扫描全表
+CREATE TABLE IF NOT EXISTS stats (
+    id INTEGER PRIMARY KEY CHECK (id = 0),
+    total_size INTEGER NOT NULL
+);
+INSERT OR IGNORE INTO stats (id, total_size) SELECT 0, COALESCE(SUM(size), 0) FROM responses;
+CREATE TRIGGER IF NOT EXISTS responses_insert AFTER INSERT ON responses BEGIN
+    UPDATE stats SET total_size = total_size + NEW.size WHERE id = 0;
+END;
+CREATE TRIGGER IF NOT EXISTS responses_update AFTER UPDATE OF size ON responses BEGIN
+    UPDATE stats SET total_size = total_size - OLD.size + NEW.size WHERE id = 0;
+END;
+CREATE TRIGGER IF NOT EXISTS responses_delete AFTER DELETE ON responses BEGIN
+    UPDATE stats SET total_size = total_size - OLD.size WHERE id = 0;
+END;
+";
+
+const TOTAL_SIZE: &str = "SELECT total_size FROM stats WHERE id = 0";
+
+const NEXT_SEQ: &str = "(SELECT COALESCE(MAX(last_used), 0) + 1 FROM responses)";
+
+/// 基于 SQLite 文件的持久化缓存，进程重启后仍然有效。
+///
+/// 每个响应按链名称与请求保存，适合批量回填历史区块、交易与铭文数据：
+/// 重新运行时已缓存的请求不再消耗 API 额度。设置 `with_max_bytes` 后，
+/// 超出容量时按最久未使用的顺序删除。
+///
+/// 作为 [`Cache`] 使用时读写错误会被忽略（视为未命中），不会导致请求失败。
+///
+/// 所有读写都在调用线程上同步执行：作为客户端的缓存时，每次 GET 请求前后的查询与写入
+/// 会短暂阻塞当前的异步工作线程（单次读写只涉及一行数据）。`vacuum` 会重写整个数据库文件，
+/// 在异步代码中应通过 `tokio::task::spawn_blocking` 调用。
+///
+/// 需要启用 `sqlite` 特性。
+pub struct SqliteCache {
+    conn: Mutex<Connection>,
+    max_bytes: Option<u64>,
+}
+
+impl fmt::Debug for SqliteCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SqliteCache")
+            .field("max_bytes", &self.max_bytes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SqliteCache {
+    /// 打开（或创建）缓存文件。
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::init(Connection::open(path).map_err(cache_error)?)
+    }
+
+    /// 创建仅存在于内存中的缓存，主要用于测试。
+    pub fn open_in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory().map_err(cache_error)?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA).map_err(cache_error)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+            max_bytes: None,
+        })
+    }
+
+    /// 缓存内容的最大字节数，超出时删除最久未使用的条目。
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// 缓存的条目数。
+    pub fn len(&self) -> Result<usize> {
+        self.conn()
+            .query_row("SELECT COUNT(*) FROM responses", [], |row| row.get(0))
+            .map_err(cache_error)
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        self.len().map(|len| len == 0)
+    }
+
+    /// 缓存内容的总字节数。
+    pub fn size_bytes(&self) -> Result<u64> {
+        self.conn()
+            .query_row(TOTAL_SIZE, [], |row| row.get(0))
+            .map_err(cache_error)
+    }
+
+    /// 删除某条链的全部缓存，返回删除的条目数。
+    pub fn invalidate_chain(&self, chain: &str) -> Result<usize> {
+        self.conn()
+            .execute(
+                "DELETE FROM responses WHERE chain = ?1",
+                params![chain.to_ascii_lowercase()],
+            )
+            .map_err(cache_error)
+    }
+
+    /// 删除某个接口的全部缓存，返回删除的条目数。
+    pub fn invalidate_endpoint(&self, uri: OkApiUri) -> Result<usize> {
+        self.conn()
+            .execute(
+                "DELETE FROM responses WHERE endpoint = ?1",
+                params![uri.as_str()],
+            )
+            .map_err(cache_error)
+    }
+
+    /// 删除已过期的条目并整理数据库文件，返回删除的条目数。
+    ///
+    /// 耗时与文件大小成正比，会阻塞调用线程。
+    pub fn vacuum(&self) -> Result<usize> {
+        let conn = self.conn();
+        let removed = conn
+            .execute(
+                "DELETE FROM responses WHERE expires_at IS NOT NULL AND expires_at <= ?1",
+                params![now_millis()],
+            )
+            .map_err(cache_error)?;
+        conn.execute_batch("VACUUM").map_err(cache_error)?;
+        Ok(removed)
+    }
+
+    fn conn(&self) -> std::sync::MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap()
+    }
+
+    fn try_get(&self, key: &str) -> rusqlite::Result<Option<Vec<u8>>> {
+        let conn = self.conn();
+        let row: Option<(Vec<u8>, Option<i64>)> = conn
+            .query_row(
+                "SELECT value, expires_at FROM responses WHERE key = ?1",
+                params![key],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        let Some((value, expires_at)) = row else {
+            return Ok(None);
+        };
+
+        let now = now_millis();
+        if expires_at.is_some_and(|at| at <= now) {
+            conn.execute("DELETE FROM responses WHERE key = ?1", params![key])?;
+            return Ok(None);
+        }
+        conn.execute(
+            &format!(
+                "UPDATE responses SET last_used = {} WHERE key = ?1",
+                NEXT_SEQ
+            ),
+            params![key],
+        )?;
+        Ok(Some(value))
+    }
+
+    fn try_put(&self, key: &str, value: &[u8], expiry: Expiry) -> rusqlite::Result<()> {
+        let now = now_millis();
+        let expires_at = match expiry {
+            Expiry::Never => None,
+            Expiry::After(ttl) => Some(now.saturating_add(ttl.as_millis() as i64)),
+        };
+        let (endpoint, chain) = match parse_uri(key) {
+            Some((uri, query)) => (
+                uri.as_str().to_string(),
+                query
+                    .get("chainShortName")
+                    .or_else(|| query.get("chainId"))
+                    .map(|c| c.to_ascii_lowercase())
+                    .unwrap_or_default(),
+            ),
+            None => (String::new(), String::new()),
+        };
+
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        tx.execute(
+            &format!(
+                "INSERT INTO responses (key, chain, endpoint, value, size, expires_at, last_used)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, {})
+                 ON CONFLICT (key) DO UPDATE SET
+                     chain = excluded.chain,
+                     endpoint = excluded.endpoint,
+                     value = excluded.value,
+                     size = excluded.size,
+                     expires_at = excluded.expires_at,
+                     last_used = excluded.last_used",
+                NEXT_SEQ
+            ),
+            params![key, chain, endpoint, value, value.len() as i64, expires_at],
+        )?;
+        if let Some(max_bytes) = self.max_bytes {
+            evict(&tx, max_bytes)?;
+        }
+        tx.commit()
+    }
+}
+
+/// 按最久未使用的顺序删除条目，直到总大小不超过 `max_bytes`。
+fn evict(conn: &Connection, max_bytes: u64) -> rusqlite::Result<()> {
+    let total: u64 = conn.query_row(TOTAL_SIZE, [], |row| row.get(0))?;
+    if total <= max_bytes {
+        return Ok(());
+    }
+
+    let mut excess = total - max_bytes;
+    let mut victims = Vec::new();
+    let mut stmt = conn.prepare("SELECT key, size FROM responses ORDER BY last_used ASC")?;
+    let mut rows = stmt.query([])?;
+    while excess > 0 {
+        let Some(row) = rows.next()? else {
+            break;
+        };
+        let size: u64 = row.get(1)?;
+        victims.push(row.get::<_, String>(0)?);
+        excess = excess.saturating_sub(size);
+    }
+    for key in victims {
+        conn.execute("DELETE FROM responses WHERE key = ?1", params![key])?;
+    }
+    Ok(())
+}
+
+impl Cache for SqliteCache {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        self.try_get(key).ok().flatten()
+    }
+
+    fn put(&self, key: &str, value: Vec<u8>, expiry: Expiry) {
+        let _ = self.try_put(key, &value, expiry);
+    }
+
+    fn invalidate(&self, key: &str) {
+        let _ = self
+            .conn()
+            .execute("DELETE FROM responses WHERE key = ?1", params![key]);
+    }
+
+    fn clear(&self) {
+        let _ = self.conn().execute("DELETE FROM responses", []);
+    }
+}
+
+fn cache_error(e: rusqlite::Error) -> OkLinkError {
+    OkLinkError::Cache(e.to_string())
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::OkLinkTestServer;
+    use std::time::Duration;
+
+    fn block_key(chain: &str, height: u64) -> String {
+        format!(
+            "{}?chainShortName={}&height={}&limit=20&page=1",
+            OkApiUri::BlockTransaction.as_str(),
+            chain,
+            height
+        )
+    }
+
+    #[test]
+    fn test_invalidate_and_size_cap() {
+        let cache = SqliteCache::open_in_memory().unwrap().with_max_bytes(10);
+        cache.put(&block_key("btc", 1), vec![0; 4], Expiry::Never);
+        cache.put(&block_key("eth", 1), vec![0; 4], Expiry::Never);
+        assert_eq!(cache.get(&block_key("btc", 1)), Some(vec![0; 4]));
+
+        // 超出 10 字节，最久未使用的 eth 条目被删除。
+        cache.put(&block_key("btc", 2), vec![0; 4], Expiry::Never);
+        assert_eq!(cache.get(&block_key("eth", 1)), None);
+        assert_eq!(cache.size_bytes().unwrap(), 8);
+
+        // 覆盖已有的条目时按新的大小计算。
+        cache.put(&block_key("btc", 2), vec![0; 2], Expiry::Never);
+        assert_eq!(cache.size_bytes().unwrap(), 6);
+
+        assert_eq!(cache.invalidate_chain("BTC").unwrap(), 2);
+        assert!(cache.is_empty().unwrap());
+        assert_eq!(cache.size_bytes().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_vacuum_removes_expired() {
+        let cache = SqliteCache::open_in_memory().unwrap();
+        cache.put(
+            &block_key("btc", 1),
+            b"old".to_vec(),
+            Expiry::After(Duration::ZERO),
+        );
+        cache.put(&block_key("btc", 2), b"new".to_vec(), Expiry::Never);
+
+        assert_eq!(cache.vacuum().unwrap(), 1);
+        assert_eq!(cache.len().unwrap(), 1);
+        assert_eq!(
+            cache
+                .invalidate_endpoint(OkApiUri::BlockTransaction)
+                .unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_survives_restart() {
+        let path = std::env::temp_dir().join(format!("oklink-cache-{}.sqlite", std::process::id()));
+        let server = OkLinkTestServer::start().await.unwrap();
+        let build = || {
+            server
+                .client_builder()
+                .cache(SqliteCache::open(&path).unwrap())
+                .build()
+                .unwrap()
+        };
+
+        let client = build();
+        client.get_chain_info().await.unwrap();
+        client
+            .get_btc_transaction_list_oklink(800000, 1, 20)
+            .await
+            .unwrap();
+        drop(client);
+
+        // 新的客户端与缓存实例读取同一个文件，不再请求服务器。
+        let client = build();
+        client
+            .get_btc_transaction_list_oklink(800000, 1, 20)
+            .await
+            .unwrap();
+        assert_eq!(server.requests_to(OkApiUri::BlockTransaction).len(), 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,351 @@
+//! 录制与回放 OkLink HTTP 流量。
+//!
+//! `Cassette` 挂载到 `HttpClient` 后有三种模式：
+//! - `Record`：正常发送请求，并把每次请求（方法、去掉 API Key 的 URI、请求体）
+//!   与原始响应写入磁带文件；
+//! - `Replay`：不访问网络，直接从磁带文件返回录制的响应；
+//! - `Passthrough`：与未挂载磁带相同，便于通过环境变量切换模式。
+//!
+//! ```ignore
+//! let mode: CassetteMode = std::env::var("OKLINK_CASSETTE").unwrap_or("replay".into()).parse()?;
+//! let cassette = Cassette::open("tests/cassettes/chain_info.json", mode)?;
+//! let client = OkLinkClient::builder().api_key(key).cassette(cassette).build()?;
+//! ```
+
+use crate::{
+    error::{OkLinkError, Result},
+    http_client::ACCESS_KEY_HEADER,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+/// 替换 API Key 的占位符。
+pub const REDACTED: &str = "[REDACTED]";
+
+/// 磁带的工作模式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// 发送真实请求并录制。
+    Record,
+    /// 只从磁带回放，不访问网络。
+    Replay,
+    /// 直接发送请求，不录制也不回放。
+    Passthrough,
+}
+
+impl FromStr for CassetteMode {
+    type Err = OkLinkError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "record" => Ok(CassetteMode::Record),
+            "replay" => Ok(CassetteMode::Replay),
+            "passthrough" | "off" => Ok(CassetteMode::Passthrough),
+            other => Err(OkLinkError::Config(format!(
+                "unknown cassette mode: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// 录制的请求。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct CassetteRequest {
+    pub method: String,
+    /// 路径与查询参数，不包含协议和主机，API Key 已被替换
+    pub uri: String,
+    pub body: Option<String>,
+}
+
+/// 录制的原始响应。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CassetteResponse {
+    pub status: u16,
+    pub retry_after: Option<String>,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: CassetteRequest,
+    response: CassetteResponse,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug)]
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    replayed: Vec<bool>,
+}
+
+/// 录制或回放 HTTP 流量的磁带，clone 后共享同一个文件。
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Arc<Mutex<CassetteState>>,
+}
+
+impl Cassette {
+    /// 打开磁带文件。
+    ///
+    /// `Record` 模式从空磁带开始，每录制一次就覆盖写入文件；
+    /// `Replay` 模式要求文件存在且格式正确。
+    pub fn open(path: impl AsRef<Path>, mode: CassetteMode) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let interactions = match mode {
+            CassetteMode::Replay => {
+                let raw = fs::read_to_string(&path).map_err(|e| {
+                    OkLinkError::Config(format!(
+                        "failed to read cassette {}: {}",
+                        path.display(),
+                        e
+                    ))
+                })?;
+                serde_json::from_str::<CassetteFile>(&raw)
+                    .map_err(|e| {
+                        OkLinkError::Config(format!("invalid cassette {}: {}", path.display(), e))
+                    })?
+                    .interactions
+            }
+            CassetteMode::Record | CassetteMode::Passthrough => Vec::new(),
+        };
+
+        Ok(Self {
+            path,
+            mode,
+            state: Arc::new(Mutex::new(CassetteState {
+                replayed: vec![false; interactions.len()],
+                interactions,
+            })),
+        })
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 磁带中的请求数量。
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().interactions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 查找与请求匹配的录制响应。
+    ///
+    /// 相同的请求按录制顺序依次返回，全部用完后重复返回最后一次录制的响应。
+    pub(crate) fn replay(&self, request: &CassetteRequest) -> Result<CassetteResponse> {
+        let mut state = self.state.lock().unwrap();
+        let matches: Vec<usize> = state
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| i.request == *request)
+            .map(|(index, _)| index)
+            .collect();
+        let index = matches
+            .iter()
+            .copied()
+            .find(|&index| !state.replayed[index])
+            .or_else(|| matches.last().copied())
+            .ok_or_else(|| {
+                OkLinkError::InvalidRequest(format!(
+                    "no cassette recording for {} {}",
+                    request.method, request.uri
+                ))
+            })?;
+        state.replayed[index] = true;
+        Ok(state.interactions[index].response.clone())
+    }
+
+    /// 追加一次录制，并立即写入文件。
+    pub(crate) fn record(
+        &self,
+        request: CassetteRequest,
+        response: CassetteResponse,
+    ) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.interactions.push(Interaction { request, response });
+        state.replayed.push(true);
+
+        let file = CassetteFile {
+            interactions: state.interactions.clone(),
+        };
+        let json = serde_json::to_string_pretty(&file).expect("cassette must serialize");
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| self.write_error(e))?;
+        }
+        fs::write(&self.path, json).map_err(|e| self.write_error(e))
+    }
+
+    fn write_error(&self, e: std::io::Error) -> OkLinkError {
+        OkLinkError::Config(format!(
+            "failed to write cassette {}: {}",
+            self.path.display(),
+            e
+        ))
+    }
+}
+
+impl CassetteRequest {
+    /// 从即将发送的请求生成录制用的请求，URI 与请求体中的 API Key 会被替换。
+    pub(crate) fn from_request(request: &reqwest::Request) -> Self {
+        let api_key = request
+            .headers()
+            .get(ACCESS_KEY_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .filter(|v| !v.is_empty());
+        let redact = |s: &str| match api_key {
+            Some(key) => s.replace(key, REDACTED),
+            None => s.to_string(),
+        };
+
+        let url = request.url();
+        let uri = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+        let body = request
+            .body()
+            .and_then(|b| b.as_bytes())
+            .map(|b| redact(&String::from_utf8_lossy(b)));
+
+        Self {
+            method: request.method().to_string(),
+            uri: redact(&uri),
+            body,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        OkLinkClient,
+        testing::{API_KEY, OkLinkTestServer},
+        types::OkApiUri,
+    };
+
+    fn cassette_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "oklink-cassette-{}-{}.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay_offline() {
+        let path = cassette_path("roundtrip");
+        let server = OkLinkTestServer::start().await.unwrap();
+
+        let cassette = Cassette::open(&path, CassetteMode::Record).unwrap();
+        let client = server.client_builder().cassette(cassette).build().unwrap();
+        let recorded = client.get_chain_info().await.unwrap();
+        client.publish_tx("0200").await.unwrap();
+
+        let raw = fs::read_to_string(&path).unwrap();
+        assert!(!raw.contains(API_KEY));
+        drop(server);
+
+        // 回放时不访问网络，基础地址指向一个不存在的服务。
+        let cassette = Cassette::open(&path, CassetteMode::Replay).unwrap();
+        assert_eq!(cassette.len(), 2);
+        let client = OkLinkClient::builder()
+            .base_url("http://127.0.0.1:9/api/v5/explorer")
+            .api_key("another-key")
+            .cassette(cassette)
+            .build()
+            .unwrap();
+        let replayed = client.get_chain_info().await.unwrap();
+        assert_eq!(replayed.data[0].last_height, recorded.data[0].last_height);
+        client.publish_tx("0200").await.unwrap();
+
+        let err = client
+            .get_address_summary_oklink("bc1q")
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(err, OkLinkError::InvalidRequest(_)));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replays_recorded_errors() {
+        let path = cassette_path("errors");
+        let server = OkLinkTestServer::start().await.unwrap();
+        server.push_fault(
+            OkApiUri::ChainInfo,
+            crate::testing::Fault::RateLimited {
+                retry_after: Some(3),
+            },
+        );
+
+        let cassette = Cassette::open(&path, CassetteMode::Record).unwrap();
+        let client = server
+            .client_builder()
+            .retry_policy(crate::retry::RetryPolicy::none())
+            .cassette(cassette)
+            .build()
+            .unwrap();
+        client.get_chain_info().await.unwrap_err();
+        client.get_chain_info().await.unwrap();
+
+        let cassette = Cassette::open(&path, CassetteMode::Replay).unwrap();
+        let client = server
+            .client_builder()
+            .retry_policy(crate::retry::RetryPolicy::none())
+            .cassette(cassette)
+            .build()
+            .unwrap();
+        let err = client.get_chain_info().await.unwrap_err();
+        assert_eq!(err.retry_after(), Some(std::time::Duration::from_secs(3)));
+        client.get_chain_info().await.unwrap();
+        assert_eq!(server.requests_to(OkApiUri::ChainInfo).len(), 2);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_redacts_api_key() {
+        let client = reqwest::Client::new();
+        let request = client
+            .post("https://www.oklink.com/api/v5/explorer/transaction/publish-tx?key=secret-key")
+            .header(ACCESS_KEY_HEADER, "secret-key")
+            .body(r#"{"signedTx":"secret-key"}"#)
+            .build()
+            .unwrap();
+
+        let recorded = CassetteRequest::from_request(&request);
+        assert_eq!(recorded.method, "POST");
+        assert_eq!(
+            recorded.uri,
+            "/api/v5/explorer/transaction/publish-tx?key=[REDACTED]"
+        );
+        assert_eq!(
+            recorded.body.as_deref(),
+            Some(r#"{"signedTx":"[REDACTED]"}"#)
+        );
+        assert_eq!(
+            "Replay".parse::<CassetteMode>().unwrap(),
+            CassetteMode::Replay
+        );
+    }
+}
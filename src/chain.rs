@@ -0,0 +1,446 @@
+use crate::{error::OkLinkError, types::BlockchainSummary};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// OkLink 支持的公链。
+///
+/// 每条链都带有 OkLink 使用的 `chainShortName`、链 ID 以及原生代币符号。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Chain {
+    Btc,
+    Bch,
+    Ltc,
+    Doge,
+    Dash,
+    Eth,
+    Etc,
+    Tron,
+    Bsc,
+    Polygon,
+    Arbitrum,
+    Optimism,
+    Base,
+    Avalanche,
+    Fantom,
+    Linea,
+    ZkSync,
+    Scroll,
+    Mantle,
+    XLayer,
+    Solana,
+}
+
+impl Chain {
+    /// 所有已知的链。
+    pub const ALL: &'static [Chain] = &[
+        Chain::Btc,
+        Chain::Bch,
+        Chain::Ltc,
+        Chain::Doge,
+        Chain::Dash,
+        Chain::Eth,
+        Chain::Etc,
+        Chain::Tron,
+        Chain::Bsc,
+        Chain::Polygon,
+        Chain::Arbitrum,
+        Chain::Optimism,
+        Chain::Base,
+        Chain::Avalanche,
+        Chain::Fantom,
+        Chain::Linea,
+        Chain::ZkSync,
+        Chain::Scroll,
+        Chain::Mantle,
+        Chain::XLayer,
+        Chain::Solana,
+    ];
+
+    /// OkLink 接口中使用的 `chainShortName`。
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Chain::Btc => "BTC",
+            Chain::Bch => "BCH",
+            Chain::Ltc => "LTC",
+            Chain::Doge => "DOGE",
+            Chain::Dash => "DASH",
+            Chain::Eth => "ETH",
+            Chain::Etc => "ETC",
+            Chain::Tron => "TRON",
+            Chain::Bsc => "BSC",
+            Chain::Polygon => "POLYGON",
+            Chain::Arbitrum => "ARBITRUM",
+            Chain::Optimism => "OP",
+            Chain::Base => "BASE",
+            Chain::Avalanche => "AVAXC",
+            Chain::Fantom => "FTM",
+            Chain::Linea => "LINEA",
+            Chain::ZkSync => "ZKSYNC",
+            Chain::Scroll => "SCROLL",
+            Chain::Mantle => "MANTLE",
+            Chain::XLayer => "XLAYER",
+            Chain::Solana => "SOL",
+        }
+    }
+
+    /// 链的全称，与 `chainFullName` 一致。
+    pub fn full_name(&self) -> &'static str {
+        match self {
+            Chain::Btc => "Bitcoin",
+            Chain::Bch => "Bitcoin Cash",
+            Chain::Ltc => "Litecoin",
+            Chain::Doge => "Dogecoin",
+            Chain::Dash => "Dash",
+            Chain::Eth => "Ethereum",
+            Chain::Etc => "Ethereum Classic",
+            Chain::Tron => "TRON",
+            Chain::Bsc => "BNB Chain",
+            Chain::Polygon => "Polygon",
+            Chain::Arbitrum => "Arbitrum One",
+            Chain::Optimism => "Optimism",
+            Chain::Base => "Base",
+            Chain::Avalanche => "Avalanche-C",
+            Chain::Fantom => "Fantom",
+            Chain::Linea => "Linea",
+            Chain::ZkSync => "zkSync Era",
+            Chain::Scroll => "Scroll",
+            Chain::Mantle => "Mantle",
+            Chain::XLayer => "X Layer",
+            Chain::Solana => "Solana",
+        }
+    }
+
+    /// 是否为 EVM 兼容链（地址为 `0x` 开头的 20 字节）。
+    pub fn is_evm(&self) -> bool {
+        matches!(
+            self,
+            Chain::Eth
+                | Chain::Etc
+                | Chain::Bsc
+                | Chain::Polygon
+                | Chain::Arbitrum
+                | Chain::Optimism
+                | Chain::Base
+                | Chain::Avalanche
+                | Chain::Fantom
+                | Chain::Linea
+                | Chain::ZkSync
+                | Chain::Scroll
+                | Chain::Mantle
+                | Chain::XLayer
+        )
+    }
+
+    /// 是否为 UTXO 模型的链，只有这些链支持 `/address/utxo`。
+    pub fn is_utxo(&self) -> bool {
+        matches!(
+            self,
+            Chain::Btc | Chain::Bch | Chain::Ltc | Chain::Doge | Chain::Dash
+        )
+    }
+
+    /// 是否支持 OkLink 的铭文接口（如 BRC-20）。
+    pub fn supports_inscriptions(&self) -> bool {
+        matches!(self, Chain::Btc | Chain::Ltc | Chain::Doge)
+    }
+
+    /// OkLink 接口中使用的链 ID（如 `chainId` 参数）。
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            Chain::Btc => 0,
+            Chain::Bch => 145,
+            Chain::Ltc => 2,
+            Chain::Doge => 3,
+            Chain::Dash => 5,
+            Chain::Eth => 1,
+            Chain::Etc => 61,
+            Chain::Tron => 195,
+            Chain::Bsc => 56,
+            Chain::Polygon => 137,
+            Chain::Arbitrum => 42161,
+            Chain::Optimism => 10,
+            Chain::Base => 8453,
+            Chain::Avalanche => 43114,
+            Chain::Fantom => 250,
+            Chain::Linea => 59144,
+            Chain::ZkSync => 324,
+            Chain::Scroll => 534352,
+            Chain::Mantle => 5000,
+            Chain::XLayer => 196,
+            Chain::Solana => 501,
+        }
+    }
+
+    /// 原生代币符号。
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Chain::Btc => "BTC",
+            Chain::Bch => "BCH",
+            Chain::Ltc => "LTC",
+            Chain::Doge => "DOGE",
+            Chain::Dash => "DASH",
+            Chain::Eth
+            | Chain::Arbitrum
+            | Chain::Optimism
+            | Chain::Base
+            | Chain::Linea
+            | Chain::ZkSync
+            | Chain::Scroll => "ETH",
+            Chain::Etc => "ETC",
+            Chain::Tron => "TRX",
+            Chain::Bsc => "BNB",
+            Chain::Polygon => "POL",
+            Chain::Avalanche => "AVAX",
+            Chain::Fantom => "FTM",
+            Chain::Mantle => "MNT",
+            Chain::XLayer => "OKB",
+            Chain::Solana => "SOL",
+        }
+    }
+
+    /// 根据链 ID 查找链。
+    pub fn from_chain_id(chain_id: u64) -> Option<Chain> {
+        Chain::ALL
+            .iter()
+            .copied()
+            .find(|c| c.chain_id() == chain_id)
+    }
+}
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.short_name())
+    }
+}
+
+impl FromStr for Chain {
+    type Err = OkLinkError;
+
+    /// 按 `chainShortName` 解析（不区分大小写），同时接受少量常见别名。
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_uppercase();
+        let alias = match name.as_str() {
+            "BITCOIN" => "BTC",
+            "ETHEREUM" => "ETH",
+            "TRX" => "TRON",
+            "BNB" => "BSC",
+            "MATIC" => "POLYGON",
+            "ARB" => "ARBITRUM",
+            "OPTIMISM" => "OP",
+            "AVAX" => "AVAXC",
+            "SOLANA" => "SOL",
+            other => other,
+        };
+        Chain::ALL
+            .iter()
+            .copied()
+            .find(|c| c.short_name() == alias)
+            .ok_or_else(|| OkLinkError::InvalidRequest(format!("unknown chain: {}", s)))
+    }
+}
+
+impl From<Chain> for String {
+    fn from(chain: Chain) -> Self {
+        chain.short_name().to_string()
+    }
+}
+
+impl Serialize for Chain {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.short_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Chain {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// [`ChainRegistry`] 中的一条链。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainEntry {
+    pub full_name: String,
+    pub short_name: String,
+    pub symbol: String,
+    /// 链 ID，[`Chain`] 中没有收录的链为 `None`
+    pub chain_id: Option<u64>,
+    /// 对应的 [`Chain`]，没有收录的链为 `None`
+    pub chain: Option<Chain>,
+    pub evm: bool,
+    pub utxo: bool,
+    pub inscriptions: bool,
+}
+
+impl From<Chain> for ChainEntry {
+    fn from(chain: Chain) -> Self {
+        Self {
+            full_name: chain.full_name().to_string(),
+            short_name: chain.short_name().to_string(),
+            symbol: chain.symbol().to_string(),
+            chain_id: Some(chain.chain_id()),
+            chain: Some(chain),
+            evm: chain.is_evm(),
+            utxo: chain.is_utxo(),
+            inscriptions: chain.supports_inscriptions(),
+        }
+    }
+}
+
+impl From<BlockchainSummary> for ChainEntry {
+    /// 名称与符号以 OkLink 返回的为准，链 ID 与能力标记来自 [`Chain`]，未收录的链均为空。
+    fn from(summary: BlockchainSummary) -> Self {
+        let chain = Chain::ALL.iter().copied().find(|c| {
+            c.short_name()
+                .eq_ignore_ascii_case(&summary.chain_short_name)
+        });
+        Self {
+            full_name: summary.chain_full_name,
+            short_name: summary.chain_short_name,
+            symbol: summary.symbol,
+            chain_id: chain.map(|c| c.chain_id()),
+            chain,
+            evm: chain.is_some_and(|c| c.is_evm()),
+            utxo: chain.is_some_and(|c| c.is_utxo()),
+            inscriptions: chain.is_some_and(|c| c.supports_inscriptions()),
+        }
+    }
+}
+
+/// OkLink 支持的链的列表，用于生成链选择列表或在运行时校验用户输入的链。
+///
+/// 通常通过 `OkLinkClient::chain_registry` 从 `/blockchain/summary` 构建；
+/// 离线时可以用 [`ChainRegistry::builtin`] 得到 [`Chain`] 中收录的链。
+///
+/// ```ignore
+/// let registry = client.chain_registry().await?;
+/// let chain = registry.validate(&user_input)?;
+/// if chain.utxo {
+///     client.on_chain(chain.chain.unwrap()).get_btc_utxo_oklink(address, 1, 100).await?;
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChainRegistry {
+    entries: Vec<ChainEntry>,
+}
+
+impl ChainRegistry {
+    /// 由 [`Chain::ALL`] 构建，不需要访问网络。
+    pub fn builtin() -> Self {
+        Self {
+            entries: Chain::ALL.iter().copied().map(ChainEntry::from).collect(),
+        }
+    }
+
+    /// 由 `/blockchain/summary` 的结果构建，重复的 `chainShortName` 只保留第一条。
+    pub fn from_summaries(summaries: impl IntoIterator<Item = BlockchainSummary>) -> Self {
+        let mut entries: Vec<ChainEntry> = Vec::new();
+        for summary in summaries {
+            if !entries
+                .iter()
+                .any(|e| e.short_name.eq_ignore_ascii_case(&summary.chain_short_name))
+            {
+                entries.push(summary.into());
+            }
+        }
+        Self { entries }
+    }
+
+    /// 按 `chainShortName` 查找（不区分大小写），同时接受 [`Chain`] 的别名，如 `ethereum`。
+    pub fn get(&self, name: &str) -> Option<&ChainEntry> {
+        let name = name.trim();
+        self.entries
+            .iter()
+            .find(|e| e.short_name.eq_ignore_ascii_case(name))
+            .or_else(|| {
+                let chain = name.parse::<Chain>().ok()?;
+                self.entries.iter().find(|e| e.chain == Some(chain))
+            })
+    }
+
+    /// 按链 ID 查找。
+    pub fn by_chain_id(&self, chain_id: u64) -> Option<&ChainEntry> {
+        self.entries.iter().find(|e| e.chain_id == Some(chain_id))
+    }
+
+    /// 与 [`Self::get`] 相同，找不到时返回 `OkLinkError::InvalidRequest`。
+    pub fn validate(&self, name: &str) -> crate::Result<&ChainEntry> {
+        self.get(name).ok_or_else(|| {
+            OkLinkError::InvalidRequest(format!("chain not supported by OkLink: {}", name))
+        })
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// 按 OkLink 返回的顺序遍历所有链。
+    pub fn iter(&self) -> impl Iterator<Item = &ChainEntry> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::OkLinkTestServer;
+
+    #[test]
+    fn test_parse_chain() {
+        assert_eq!("btc".parse::<Chain>().unwrap(), Chain::Btc);
+        assert_eq!("Optimism".parse::<Chain>().unwrap(), Chain::Optimism);
+        assert_eq!(Chain::from_chain_id(56), Some(Chain::Bsc));
+        assert!("unknown".parse::<Chain>().is_err());
+        for chain in Chain::ALL {
+            assert_eq!(chain.short_name().parse::<Chain>().unwrap(), *chain);
+        }
+    }
+
+    #[test]
+    fn test_builtin_registry() {
+        let registry = ChainRegistry::builtin();
+        assert_eq!(registry.len(), Chain::ALL.len());
+
+        let btc = registry.get("btc").unwrap();
+        assert_eq!(btc.full_name, "Bitcoin");
+        assert!(btc.utxo && btc.inscriptions && !btc.evm);
+        assert_eq!(registry.get("ethereum").unwrap().short_name, "ETH");
+        assert_eq!(registry.by_chain_id(56).unwrap().chain, Some(Chain::Bsc));
+        assert!(registry.validate("nope").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_registry_from_summary() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let client = server.client().unwrap();
+        let registry = client.chain_registry().await.unwrap();
+
+        let request = server.requests_to(crate::types::OkApiUri::BlockchainSummary);
+        assert_eq!(request[0].param("chainShortName"), None);
+
+        let names: Vec<_> = registry.iter().map(|e| e.short_name.as_str()).collect();
+        assert_eq!(names, ["BTC", "ETH", "BSC", "STARKNET"]);
+
+        let bsc = registry.validate("bsc").unwrap();
+        assert_eq!(bsc.full_name, "BNB Chain");
+        assert_eq!(bsc.chain_id, Some(56));
+        assert!(bsc.evm && !bsc.utxo);
+
+        // 未收录在 `Chain` 中的链仍然可以被校验，但没有链 ID 与能力标记
+        let starknet = registry.validate("starknet").unwrap();
+        assert_eq!(starknet.symbol, "STRK");
+        assert_eq!(starknet.chain, None);
+        assert!(!starknet.evm && !starknet.utxo && !starknet.inscriptions);
+        assert!(!registry.contains("SOL"));
+    }
+}
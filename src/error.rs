@@ -0,0 +1,120 @@
+use reqwest::StatusCode;
+use std::time::Duration;
+
+/// OKLink 业务层的限流错误码。
+pub const RATE_LIMIT_CODES: &[&str] = &["50011"];
+
+/// SDK 统一的结果类型。
+pub type Result<T> = std::result::Result<T, OkLinkError>;
+
+/// `OkLinkError` 描述了调用 OkLink API 时可能出现的所有错误。
+#[derive(Debug, thiserror::Error)]
+pub enum OkLinkError {
+    /// 网络层错误（连接失败、超时等）。
+    #[error("transport error: {0}")]
+    Transport(#[from] reqwest::Error),
+
+    /// 服务端返回了非 2xx 的 HTTP 状态码。
+    #[error("http status {status}: {body}")]
+    Http {
+        /// HTTP 状态码
+        status: StatusCode,
+        /// 原始响应体
+        body: String,
+        /// `Retry-After` 响应头给出的等待时间
+        retry_after: Option<Duration>,
+    },
+
+    /// OkLink 返回了非 `"0"` 的业务错误码。
+    #[error("oklink api error {code}: {msg}")]
+    Api {
+        /// OkLink 错误码
+        code: String,
+        /// OkLink 错误消息
+        msg: String,
+    },
+
+    /// 触发了 OkLink 的限流（HTTP 429 或限流业务码）。
+    #[error("rate limited: {msg}")]
+    RateLimited {
+        /// HTTP 状态码
+        status: StatusCode,
+        /// OkLink 错误码（HTTP 层限流时为空）
+        code: Option<String>,
+        /// 错误消息
+        msg: String,
+        /// `Retry-After` 响应头给出的等待时间
+        retry_after: Option<Duration>,
+    },
+
+    /// 响应体无法反序列化为目标类型。
+    #[error("failed to decode response: {source}")]
+    Decode {
+        /// serde 错误
+        #[source]
+        source: serde_json::Error,
+        /// 原始响应体
+        body: String,
+    },
+
+    /// 请求参数不合法。
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+
+    /// 客户端配置不合法（如 API Key 包含非法字符）。
+    #[error("invalid configuration: {0}")]
+    Config(String),
+
+    /// 缓存后端读写失败（如 SQLite 文件损坏）。
+    #[error("cache error: {0}")]
+    Cache(String),
+
+    /// `tower` 中间件返回的其他错误（如超时、熔断）。
+    #[error("service error: {0}")]
+    Service(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    /// Key 池中所有 API Key 都处于冷却中。
+    #[error("no api key available")]
+    NoAvailableKey {
+        /// 最早恢复可用的等待时间
+        retry_after: Option<Duration>,
+    },
+}
+
+impl OkLinkError {
+    /// 返回 OkLink 业务错误码（如果有）。
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            OkLinkError::Api { code, .. } => Some(code),
+            OkLinkError::RateLimited { code, .. } => code.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// 返回 HTTP 状态码（如果有）。
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            OkLinkError::Transport(e) => e.status(),
+            OkLinkError::Http { status, .. } | OkLinkError::RateLimited { status, .. } => {
+                Some(*status)
+            }
+            _ => None,
+        }
+    }
+
+    /// 返回建议的重试等待时间：来自 `Retry-After` 响应头，
+    /// 或者 Key 池中最早的 Key 结束冷却前的时间。
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            OkLinkError::Http { retry_after, .. }
+            | OkLinkError::RateLimited { retry_after, .. }
+            | OkLinkError::NoAvailableKey { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// 是否为限流错误。
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, OkLinkError::RateLimited { .. })
+    }
+}
@@ -1,23 +1,57 @@
-use anyhow::{Context, anyhow};
+use crate::{
+    cache::{Cache, CachePolicy},
+    cassette::{Cassette, CassetteMode, CassetteRequest, CassetteResponse},
+    error::{OkLinkError, RATE_LIMIT_CODES, Result},
+    key_pool::KeyPool,
+    rate_limit::RateLimiter,
+    resp::{Response, ResponseMeta},
+    retry::{RetryPolicy, parse_retry_after},
+    telemetry::RequestMeta,
+};
 use reqwest::{
-    Client, Method,
-    header::{HeaderMap, HeaderValue},
+    Client, Method, RequestBuilder, StatusCode,
+    header::{HeaderMap, HeaderValue, RETRY_AFTER},
 };
 use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use std::{sync::Arc, time::Duration};
+use tokio::time::Instant;
+use tracing::Instrument;
+
+/// OkLink API Key 所在的请求头。
+pub const ACCESS_KEY_HEADER: &str = "Ok-Access-Key";
 
 #[derive(Debug, Clone)]
 pub struct HttpClient {
     client: Client,
     base_url: String,
     headers: HeaderMap,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    key_pool: Option<KeyPool>,
+    cassette: Option<Cassette>,
+    cache: Option<Arc<dyn Cache>>,
+    cache_policy: CachePolicy,
+    raw_body: bool,
+    #[cfg(feature = "tower")]
+    service: Option<crate::middleware::BoxService>,
 }
 
 impl HttpClient {
-    pub fn new(base_url:String, headers: Option<HeaderMap>) -> Self {
+    pub fn new(base_url: String, headers: Option<HeaderMap>) -> Self {
         HttpClient {
             client: Client::new(),
             base_url: base_url.to_owned(),
             headers: headers.unwrap_or_default(),
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: None,
+            key_pool: None,
+            cassette: None,
+            cache: None,
+            cache_policy: CachePolicy::default(),
+            raw_body: false,
+            #[cfg(feature = "tower")]
+            service: None,
         }
     }
 
@@ -26,7 +60,94 @@ impl HttpClient {
         self
     }
 
-    pub async fn get<T>(&self, uri: &str, is_json: bool) -> anyhow::Result<T>
+    /// 使用 `tower` service 作为传输层代替 `reqwest::Client`，详见 [`crate::middleware`]。
+    ///
+    /// 不会修改重试策略，service 中已经包含 `RetryLayer` 时应设置 `RetryPolicy::none()`；
+    /// 通过 `OkLinkClientBuilder::service` 设置时会自动关闭客户端自身的重试。
+    ///
+    /// 需要启用 `tower` 特性。
+    #[cfg(feature = "tower")]
+    pub fn with_service<S>(self, service: S) -> Self
+    where
+        S: tower::Service<
+                crate::middleware::HttpRequest,
+                Response = crate::middleware::HttpResponse,
+            > + Clone
+            + Send
+            + Sync
+            + 'static,
+        S::Error: Into<crate::middleware::BoxError>,
+        S::Future: Send + 'static,
+    {
+        self.with_boxed_service(crate::middleware::boxed(service))
+    }
+
+    #[cfg(feature = "tower")]
+    pub(crate) fn with_boxed_service(mut self, service: crate::middleware::BoxService) -> Self {
+        self.service = Some(service);
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// 使用 Key 池为每个请求选择 API Key，并移除固定的 `Ok-Access-Key` 请求头。
+    pub fn with_key_pool(mut self, key_pool: KeyPool) -> Self {
+        self.headers.remove(ACCESS_KEY_HEADER);
+        self.key_pool = Some(key_pool);
+        self
+    }
+
+    /// 挂载录制/回放磁带，详见 [`Cassette`]。
+    pub fn with_cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// 启用响应缓存，GET 请求会先查询缓存。
+    pub fn with_cache<C: Cache + 'static>(self, cache: C) -> Self {
+        self.with_shared_cache(Arc::new(cache))
+    }
+
+    pub(crate) fn with_shared_cache(mut self, cache: Arc<dyn Cache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// 设置缓存策略，只在启用缓存时生效。
+    pub fn with_cache_policy(mut self, cache_policy: CachePolicy) -> Self {
+        self.cache_policy = cache_policy;
+        self
+    }
+
+    /// 在 `*_with_meta` 返回的 [`ResponseMeta`] 中保留原始响应体。
+    pub fn with_raw_body(mut self, raw_body: bool) -> Self {
+        self.raw_body = raw_body;
+        self
+    }
+
+    pub async fn get<T>(&self, uri: &str, is_json: bool) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.get_with_meta(uri, is_json)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 `get` 相同，同时返回状态码、响应头、耗时等元数据。
+    pub async fn get_with_meta<T>(&self, uri: &str, is_json: bool) -> Result<Response<T>>
     where
         T: DeserializeOwned,
     {
@@ -36,39 +157,80 @@ impl HttpClient {
         }
 
         let url = self.construct_url(uri);
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .send()
-            .await
-            .context("Failed to send GET request")?;
+        let meta = RequestMeta::new("GET", uri, None);
+        let request = || self.client.get(&url).headers(headers.clone());
+        let Some(cache) = &self.cache else {
+            return self.execute(&meta, true, request).await;
+        };
 
-        response
-            .json::<T>()
-            .await
-            .context("Failed to parse response as JSON")
+        let started = Instant::now();
+        if let Some(cached) = cache.get(uri) {
+            match serde_json::from_slice(&cached) {
+                Ok(data) => {
+                    meta.record_cache_hit();
+                    return Ok(Response {
+                        data,
+                        meta: ResponseMeta {
+                            status: StatusCode::OK,
+                            headers: HeaderMap::new(),
+                            elapsed: started.elapsed(),
+                            attempts: 0,
+                            from_cache: true,
+                            body: self.raw_body.then_some(cached),
+                        },
+                    });
+                }
+                // 缓存的格式与目标类型不符（如升级后结构变化），重新请求。
+                Err(_) => cache.invalidate(uri),
+            }
+        }
+
+        let Response {
+            data: value,
+            meta: response_meta,
+        } = self.execute::<Value, _>(&meta, true, request).await?;
+        self.cache_policy.observe(uri, &value);
+        // 序列化失败时只跳过缓存写入，不影响本次请求的结果。
+        if let Some(expiry) = self.cache_policy.expiry_for(uri, &value)
+            && let Ok(body) = serde_json::to_vec(&value)
+        {
+            cache.put(uri, body, expiry);
+        }
+        let data = serde_json::from_value(value.clone()).map_err(|source| OkLinkError::Decode {
+            source,
+            body: value.to_string(),
+        })?;
+        Ok(Response {
+            data,
+            meta: response_meta,
+        })
     }
 
-    pub async fn post<T, R>(&self, uri: &str, body: &T) -> anyhow::Result<R>
+    pub async fn post<T, R>(&self, uri: &str, body: &T) -> Result<R>
     where
         T: Serialize,
         R: DeserializeOwned,
     {
-        let url = self.construct_url(uri);
-        let response = self
-            .client
-            .post(&url)
-            .headers(self.headers.clone())
-            .json(body)
-            .send()
+        self.post_with_meta(uri, body)
             .await
-            .context("Failed to send POST request")?;
+            .map(Response::into_data)
+    }
 
-        response
-            .json::<R>()
-            .await
-            .context("Failed to parse response as JSON")
+    /// 与 `post` 相同，同时返回状态码、响应头、耗时等元数据。
+    pub async fn post_with_meta<T, R>(&self, uri: &str, body: &T) -> Result<Response<R>>
+    where
+        T: Serialize,
+        R: DeserializeOwned,
+    {
+        let url = self.construct_url(uri);
+        let meta = RequestMeta::new("POST", uri, serde_json::to_value(body).ok().as_ref());
+        self.execute(&meta, false, || {
+            self.client
+                .post(&url)
+                .headers(self.headers.clone())
+                .json(body)
+        })
+        .await
     }
 
     pub async fn request<T>(
@@ -77,7 +239,7 @@ impl HttpClient {
         method: Method,
         body: Option<&T>,
         is_json: bool,
-    ) -> anyhow::Result<T>
+    ) -> Result<T>
     where
         T: DeserializeOwned + Serialize,
     {
@@ -85,13 +247,397 @@ impl HttpClient {
             Method::GET => self.get(uri, is_json).await,
             Method::POST => match body {
                 Some(b) => self.post(uri, b).await,
-                None => Err(anyhow!("POST parameter cannot be empty")),
+                None => Err(OkLinkError::InvalidRequest(
+                    "POST parameter cannot be empty".to_string(),
+                )),
             },
-            _ => Err(anyhow!("Unsupported HTTP method")),
+            _ => Err(OkLinkError::InvalidRequest(format!(
+                "Unsupported HTTP method: {}",
+                method
+            ))),
         }
     }
 
     fn construct_url(&self, uri: &str) -> String {
         format!("{}{}", self.base_url, uri)
     }
+
+    /// 按照重试策略发送请求，`idempotent` 为 `false` 的请求默认只发送一次。
+    async fn execute<T, F>(
+        &self,
+        meta: &RequestMeta,
+        idempotent: bool,
+        build: F,
+    ) -> Result<Response<T>>
+    where
+        T: DeserializeOwned,
+        F: Fn() -> RequestBuilder,
+    {
+        let max_attempts = self.retry_policy.max_attempts_for(idempotent);
+        let first_started = Instant::now();
+        let mut attempt = 1;
+        loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire().await;
+            }
+            let (span, result) = self.attempt(meta, attempt, &build).await;
+            match result {
+                Err(err) if attempt < max_attempts && self.retry_policy.is_retryable(&err) => {
+                    let delay = self.retry_delay(attempt, &err);
+                    tracing::info!(
+                        parent: &span,
+                        delay_ms = delay.as_millis() as u64,
+                        "retrying oklink request"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                other => {
+                    return other.map(|mut response| {
+                        response.meta.elapsed = first_started.elapsed();
+                        response.meta.attempts = attempt;
+                        response
+                    });
+                }
+            }
+        }
+    }
+
+    /// 发送一次请求。Key 池中没有可用的 Key 时不发送，返回 `OkLinkError::NoAvailableKey`，
+    /// 由重试策略决定是否等待。
+    async fn attempt<T, F>(
+        &self,
+        meta: &RequestMeta,
+        attempt: u32,
+        build: &F,
+    ) -> (tracing::Span, Result<Response<T>>)
+    where
+        T: DeserializeOwned,
+        F: Fn() -> RequestBuilder,
+    {
+        let lease = match self.key_pool.as_ref().map(KeyPool::acquire).transpose() {
+            Ok(lease) => lease,
+            Err(err) => return (meta.span(attempt, None), Err(err)),
+        };
+        let mut request = build();
+        if let Some(lease) = &lease {
+            request = request.header(ACCESS_KEY_HEADER, lease.header.clone());
+        }
+
+        let api_key = lease
+            .as_ref()
+            .map(|l| &l.header)
+            .or_else(|| self.headers.get(ACCESS_KEY_HEADER))
+            .and_then(|v| v.to_str().ok());
+        let span = meta.span(attempt, api_key);
+        let started = Instant::now();
+        let result = self.send(request).instrument(span.clone()).await;
+        let live = self
+            .cassette
+            .as_ref()
+            .is_none_or(|c| c.mode() != CassetteMode::Replay);
+        meta.record(&span, started.elapsed(), live, &result);
+        if let (Some(pool), Some(lease), Err(err)) = (&self.key_pool, &lease, &result) {
+            pool.report_failure(lease, err);
+        }
+        (span, result)
+    }
+
+    /// 重试前的等待时间：所有 Key 都在冷却中时，至少等到最早的 Key 恢复可用。
+    fn retry_delay(&self, attempt: u32, err: &OkLinkError) -> Duration {
+        let delay = self.retry_policy.delay_for(attempt, err);
+        let cooldown = self.key_pool.as_ref().and_then(KeyPool::available_in);
+        delay.max(cooldown.unwrap_or_default())
+    }
+
+    /// 发送请求并解析响应；挂载了磁带时按磁带模式录制或回放。
+    async fn send<T>(&self, request: RequestBuilder) -> Result<Response<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let request = request.build()?;
+        let cassette = self
+            .cassette
+            .as_ref()
+            .filter(|c| c.mode() != CassetteMode::Passthrough);
+        let recorded = cassette.map(|_| CassetteRequest::from_request(&request));
+
+        let response = match (cassette, recorded) {
+            (Some(cassette), Some(recorded)) if cassette.mode() == CassetteMode::Replay => {
+                RawResponse::from_cassette(cassette.replay(&recorded)?)?
+            }
+            (Some(cassette), Some(recorded)) => {
+                let response = self.transport(request).await?;
+                cassette.record(
+                    recorded,
+                    CassetteResponse {
+                        status: response.status.as_u16(),
+                        retry_after: response.retry_after_header().map(str::to_string),
+                        body: String::from_utf8_lossy(&response.body).into_owned(),
+                    },
+                )?;
+                response
+            }
+            _ => self.transport(request).await?,
+        };
+
+        let data = decode_body(
+            response.status,
+            response.retry_after_header().and_then(parse_retry_after),
+            &response.body,
+        )?;
+        Ok(Response {
+            data,
+            meta: ResponseMeta {
+                status: response.status,
+                headers: response.headers,
+                elapsed: Duration::ZERO,
+                attempts: 1,
+                from_cache: false,
+                body: self.raw_body.then_some(response.body),
+            },
+        })
+    }
+
+    /// 通过传输层发送请求：设置了 `tower` service 时交给 service，否则使用 `reqwest::Client`。
+    async fn transport(&self, request: reqwest::Request) -> Result<RawResponse> {
+        #[cfg(feature = "tower")]
+        if let Some(service) = &self.service {
+            return crate::middleware::call(service.clone(), request).await;
+        }
+
+        let response = self.client.execute(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?.to_vec();
+        Ok(RawResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// 传输层返回的原始响应。
+pub(crate) struct RawResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl RawResponse {
+    fn from_cassette(response: CassetteResponse) -> Result<Self> {
+        let status = StatusCode::from_u16(response.status).map_err(|_| {
+            OkLinkError::Config(format!("invalid cassette status {}", response.status))
+        })?;
+        let mut headers = HeaderMap::new();
+        if let Some(value) = response
+            .retry_after
+            .and_then(|v| HeaderValue::from_str(&v).ok())
+        {
+            headers.insert(RETRY_AFTER, value);
+        }
+        Ok(Self {
+            status,
+            headers,
+            body: response.body.into_bytes(),
+        })
+    }
+
+    fn retry_after_header(&self) -> Option<&str> {
+        self.headers.get(RETRY_AFTER).and_then(|v| v.to_str().ok())
+    }
+}
+
+/// 将 HTTP 状态码与响应体解析为目标类型，并检查 OkLink 的 `code` 字段。
+pub(crate) fn decode_body<T>(
+    status: StatusCode,
+    retry_after: Option<Duration>,
+    body: &[u8],
+) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let text = || String::from_utf8_lossy(body).into_owned();
+
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(OkLinkError::RateLimited {
+            status,
+            code: None,
+            msg: text(),
+            retry_after,
+        });
+    }
+    if !status.is_success() {
+        return Err(OkLinkError::Http {
+            status,
+            body: text(),
+            retry_after,
+        });
+    }
+
+    let value: Value = serde_json::from_slice(body).map_err(|source| OkLinkError::Decode {
+        source,
+        body: text(),
+    })?;
+
+    if let Some(code) = value.get("code").and_then(code_to_string)
+        && code != "0"
+    {
+        let msg = value
+            .get("msg")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        if RATE_LIMIT_CODES.contains(&code.as_str()) {
+            return Err(OkLinkError::RateLimited {
+                status,
+                code: Some(code),
+                msg,
+                retry_after,
+            });
+        }
+        return Err(OkLinkError::Api { code, msg });
+    }
+
+    serde_json::from_value(value).map_err(|source| OkLinkError::Decode {
+        source,
+        body: text(),
+    })
+}
+
+fn code_to_string(code: &Value) -> Option<String> {
+    match code {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resp::OkLinkResp;
+    use crate::types::{BlockChainInfo, OkApiUri};
+    use crate::{
+        cache::LruCache,
+        key_pool::KeyPool,
+        testing::{API_KEY, Fault, OkLinkTestServer},
+    };
+
+    #[test]
+    fn test_decode_api_error() {
+        let body = br#"{"code":"50038","msg":"chain not supported","data":[]}"#;
+        let err =
+            decode_body::<OkLinkResp<BlockChainInfo>>(StatusCode::OK, None, body).unwrap_err();
+        assert!(matches!(err, OkLinkError::Api { ref code, .. } if code == "50038"));
+    }
+
+    #[test]
+    fn test_decode_rate_limited() {
+        let body = br#"{"code":"50011","msg":"Too Many Requests","data":[]}"#;
+        let err = decode_body::<Value>(StatusCode::OK, None, body).unwrap_err();
+        assert!(err.is_rate_limited());
+        assert_eq!(err.code(), Some("50011"));
+
+        let err =
+            decode_body::<Value>(StatusCode::TOO_MANY_REQUESTS, None, b"slow down").unwrap_err();
+        assert!(err.is_rate_limited());
+        assert_eq!(err.status(), Some(StatusCode::TOO_MANY_REQUESTS));
+    }
+
+    #[test]
+    fn test_decode_failure_keeps_body() {
+        let body = br#"{"code":"0","msg":"","data":[{"unexpected":1}]}"#;
+        let err =
+            decode_body::<OkLinkResp<BlockChainInfo>>(StatusCode::OK, None, body).unwrap_err();
+        match err {
+            OkLinkError::Decode { body: raw, .. } => assert!(raw.contains("unexpected")),
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_response_meta() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let client = server
+            .client_builder()
+            .retry_policy(RetryPolicy::default().with_base_delay(Duration::from_millis(1)))
+            .cache(LruCache::new(10))
+            .raw_body(true)
+            .build()
+            .unwrap();
+
+        server.push_fault(OkApiUri::ChainInfo, Fault::Status(503));
+        let response = client.get_chain_info_with_meta().await.unwrap();
+        assert_eq!(response.meta.status, StatusCode::OK);
+        assert_eq!(response.meta.attempts, 2);
+        assert!(!response.meta.from_cache);
+        assert!(response.meta.request_id().unwrap().starts_with("req-"));
+        assert!(response.meta.body_text().unwrap().contains("lastHeight"));
+        assert_eq!(response.data.data[0].last_height, 812742);
+
+        let cached = client.get_chain_info_with_meta().await.unwrap();
+        assert!(cached.meta.from_cache);
+        assert_eq!(cached.meta.attempts, 0);
+        assert!(cached.meta.body.is_some());
+
+        let response = client.publish_tx_with_meta("0200").await.unwrap();
+        assert_eq!(response.meta.attempts, 1);
+        assert!(response.meta.header("content-type").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_retries_without_parking_key() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let pool = KeyPool::new([API_KEY])
+            .unwrap()
+            .with_cooldown(Duration::from_secs(60));
+        let client = crate::OkLinkClient::builder()
+            .base_url(server.base_url())
+            .key_pool(pool.clone())
+            .retry_policy(RetryPolicy::default().with_base_delay(Duration::from_millis(1)))
+            .build()
+            .unwrap();
+
+        // 每秒限流只按退避时间重试，Key 不会进入冷却。
+        server.push_fault(
+            OkApiUri::ChainInfo,
+            Fault::RateLimited { retry_after: None },
+        );
+        let response = client.get_chain_info_with_meta().await.unwrap();
+        assert_eq!(response.meta.attempts, 2);
+        let usage = pool.usage();
+        assert_eq!(usage[0].failures, 1);
+        assert_eq!(usage[0].requests, 2);
+        assert_eq!(usage[0].parked_for, None);
+    }
+
+    #[tokio::test]
+    async fn test_single_key_waits_for_cooldown() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let pool = KeyPool::new([API_KEY])
+            .unwrap()
+            .with_cooldown(Duration::from_millis(50));
+        let client = crate::OkLinkClient::builder()
+            .base_url(server.base_url())
+            .key_pool(pool.clone())
+            .retry_policy(RetryPolicy::default().with_base_delay(Duration::from_millis(1)))
+            .build()
+            .unwrap();
+
+        // 鉴权失败让唯一的 Key 进入冷却，下一次请求要等到 Key 恢复后再发送。
+        server.push_fault(OkApiUri::ChainInfo, Fault::Status(401));
+        let err = client.get_chain_info().await.unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::UNAUTHORIZED));
+        assert!(pool.usage()[0].parked_for.is_some());
+
+        let started = Instant::now();
+        let response = client.get_chain_info_with_meta().await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(40));
+        assert_eq!(response.meta.attempts, 2);
+        assert_eq!(server.requests_to(OkApiUri::ChainInfo).len(), 2);
+        assert_eq!(pool.usage()[0].failures, 1);
+        assert_eq!(pool.usage()[0].requests, 2);
+    }
 }
@@ -0,0 +1,374 @@
+use crate::{
+    error::{OkLinkError, Result},
+    rate_limit::{unix_now, utc_day},
+};
+use reqwest::{StatusCode, header::HeaderValue};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::Instant;
+
+/// 默认会导致 API Key 被暂停使用的 OkLink 错误码（Key 无效、被冻结等）。
+///
+/// 每日额度用尽的错误码可以通过 `KeyPool::with_park_codes` 追加；
+/// 每秒限流（HTTP 429 / `50011`）不会暂停 Key，只按 `Retry-After` 等待后重试。
+pub const DEFAULT_PARK_CODES: &[&str] = &["50100", "50111"];
+
+/// API Key 的选择策略。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeySelection {
+    /// 轮询
+    #[default]
+    RoundRobin,
+    /// 选择今日使用次数最少的 Key
+    LeastUsed,
+}
+
+/// `KeyPool` 管理多个 OkLink API Key，将请求分摊到不同的 Key 上。
+///
+/// 返回鉴权错误或每日额度错误的 Key 会被暂停一段冷却时间，期间不会被选中。
+/// `KeyPool` 可以 clone，clone 后的实例共享同一份状态。
+#[derive(Debug, Clone)]
+pub struct KeyPool {
+    state: Arc<Mutex<PoolState>>,
+    selection: KeySelection,
+    cooldown: Duration,
+    daily_limit: Option<u64>,
+    park_codes: Vec<String>,
+}
+
+#[derive(Debug)]
+struct PoolState {
+    keys: Vec<KeyState>,
+    cursor: usize,
+}
+
+#[derive(Debug)]
+struct KeyState {
+    key: String,
+    header: HeaderValue,
+    requests: u64,
+    failures: u64,
+    requests_today: u64,
+    day: u64,
+    parked_until: Option<Instant>,
+}
+
+/// 单个 API Key 的使用统计。
+#[derive(Debug, Clone)]
+pub struct KeyUsage {
+    /// 脱敏后的 Key
+    pub key: String,
+    /// 累计请求次数
+    pub requests: u64,
+    /// 累计失败次数
+    pub failures: u64,
+    /// 今日（UTC）请求次数
+    pub requests_today: u64,
+    /// 今日剩余额度，未设置每日额度时为 `None`
+    pub remaining_today: Option<u64>,
+    /// 剩余冷却时间，未被暂停时为 `None`
+    pub parked_for: Option<Duration>,
+}
+
+/// 一次请求所使用的 Key。
+#[derive(Debug, Clone)]
+pub(crate) struct KeyLease {
+    pub(crate) index: usize,
+    pub(crate) header: HeaderValue,
+}
+
+impl KeyPool {
+    /// 使用一组 API Key 创建 `KeyPool`。
+    ///
+    /// Key 列表为空、Key 为空白或包含非法字符时返回 `OkLinkError::Config`。
+    pub fn new<I, S>(keys: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let today = utc_day(unix_now());
+        let keys = keys
+            .into_iter()
+            .map(|key| {
+                let key = key.into();
+                if key.trim().is_empty() {
+                    return Err(OkLinkError::Config("api key must not be empty".into()));
+                }
+                let header = HeaderValue::from_str(&key).map_err(|_| {
+                    OkLinkError::Config("api key contains invalid characters".into())
+                })?;
+                Ok(KeyState {
+                    key,
+                    header,
+                    requests: 0,
+                    failures: 0,
+                    requests_today: 0,
+                    day: today,
+                    parked_until: None,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if keys.is_empty() {
+            return Err(OkLinkError::Config(
+                "key pool requires at least one api key".into(),
+            ));
+        }
+
+        Ok(KeyPool {
+            state: Arc::new(Mutex::new(PoolState { keys, cursor: 0 })),
+            selection: KeySelection::default(),
+            cooldown: Duration::from_secs(60),
+            daily_limit: None,
+            park_codes: DEFAULT_PARK_CODES.iter().map(|c| c.to_string()).collect(),
+        })
+    }
+
+    pub fn with_selection(mut self, selection: KeySelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// 设置每个 Key 的每日额度，用于在 `usage` 中计算剩余额度。
+    pub fn with_daily_limit(mut self, daily_limit: u64) -> Self {
+        self.daily_limit = Some(daily_limit);
+        self
+    }
+
+    /// 设置会让 Key 进入冷却的 OkLink 错误码，默认为 `DEFAULT_PARK_CODES`。
+    pub fn with_park_codes(mut self, codes: Vec<String>) -> Self {
+        self.park_codes = codes;
+        self
+    }
+
+    /// 返回每个 Key 的使用统计。
+    pub fn usage(&self) -> Vec<KeyUsage> {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        let today = utc_day(unix_now());
+        state
+            .keys
+            .iter_mut()
+            .map(|k| {
+                k.roll_day(today);
+                KeyUsage {
+                    key: redact_key(&k.key),
+                    requests: k.requests,
+                    failures: k.failures,
+                    requests_today: k.requests_today,
+                    remaining_today: self
+                        .daily_limit
+                        .map(|limit| limit.saturating_sub(k.requests_today)),
+                    parked_for: k
+                        .parked_until
+                        .filter(|until| *until > now)
+                        .map(|until| until - now),
+                }
+            })
+            .collect()
+    }
+
+    /// 选出下一个可用的 Key，所有 Key 都在冷却中时返回 `OkLinkError::NoAvailableKey`。
+    pub(crate) fn acquire(&self) -> Result<KeyLease> {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        let today = utc_day(unix_now());
+        let len = state.keys.len();
+        state.keys.iter_mut().for_each(|k| k.roll_day(today));
+
+        let available = |k: &KeyState| k.parked_until.is_none_or(|until| until <= now);
+        let index = match self.selection {
+            KeySelection::RoundRobin => (0..len)
+                .map(|offset| (state.cursor + offset) % len)
+                .find(|i| available(&state.keys[*i])),
+            KeySelection::LeastUsed => state
+                .keys
+                .iter()
+                .enumerate()
+                .filter(|(_, k)| available(k))
+                .min_by_key(|(_, k)| k.requests_today)
+                .map(|(i, _)| i),
+        };
+
+        let Some(index) = index else {
+            let retry_after = state.earliest_unpark(now);
+            return Err(OkLinkError::NoAvailableKey { retry_after });
+        };
+
+        state.cursor = (index + 1) % len;
+        let key = &mut state.keys[index];
+        key.parked_until = None;
+        key.requests += 1;
+        key.requests_today += 1;
+        Ok(KeyLease {
+            index,
+            header: key.header.clone(),
+        })
+    }
+
+    /// 所有 Key 都在冷却中时，返回最早的 Key 恢复可用前的等待时间；有可用的 Key 时返回 `None`。
+    pub(crate) fn available_in(&self) -> Option<Duration> {
+        let state = self.state.lock().unwrap();
+        let now = Instant::now();
+        if state
+            .keys
+            .iter()
+            .any(|k| k.parked_until.is_none_or(|until| until <= now))
+        {
+            return None;
+        }
+        state.earliest_unpark(now)
+    }
+
+    /// 记录一次请求失败，鉴权或每日额度错误会让该 Key 进入冷却。
+    pub(crate) fn report_failure(&self, lease: &KeyLease, err: &OkLinkError) {
+        let mut state = self.state.lock().unwrap();
+        let key = &mut state.keys[lease.index];
+        key.failures += 1;
+        if self.should_park(err) {
+            let cooldown = err.retry_after().unwrap_or_default().max(self.cooldown);
+            key.parked_until = Some(Instant::now() + cooldown);
+        }
+    }
+
+    fn should_park(&self, err: &OkLinkError) -> bool {
+        match err {
+            OkLinkError::Http { status, .. } => {
+                *status == StatusCode::UNAUTHORIZED || *status == StatusCode::FORBIDDEN
+            }
+            OkLinkError::Api { code, .. } => self.park_codes.contains(code),
+            _ => false,
+        }
+    }
+}
+
+impl PoolState {
+    fn earliest_unpark(&self, now: Instant) -> Option<Duration> {
+        self.keys
+            .iter()
+            .filter_map(|k| k.parked_until)
+            .min()
+            .map(|until| until.saturating_duration_since(now))
+    }
+}
+
+impl KeyState {
+    fn roll_day(&mut self, today: u64) {
+        if self.day != today {
+            self.day = today;
+            self.requests_today = 0;
+        }
+    }
+}
+
+/// 对 API Key 脱敏，只保留首尾各 4 个字符。
+pub(crate) fn redact_key(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    if chars.len() <= 8 {
+        return "****".to_string();
+    }
+    let head: String = chars[..4].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}****{}", head, tail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auth_error() -> OkLinkError {
+        OkLinkError::Api {
+            code: "50111".to_string(),
+            msg: "Invalid OK-ACCESS-KEY".to_string(),
+        }
+    }
+
+    fn rate_limit_error() -> OkLinkError {
+        OkLinkError::RateLimited {
+            status: StatusCode::TOO_MANY_REQUESTS,
+            code: Some("50011".to_string()),
+            msg: String::new(),
+            retry_after: Some(Duration::from_secs(1)),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_round_robin_and_cooldown() {
+        let pool = KeyPool::new(["key-a-0000", "key-b-0000"])
+            .unwrap()
+            .with_cooldown(Duration::from_secs(30));
+
+        let first = pool.acquire().unwrap();
+        let second = pool.acquire().unwrap();
+        assert_ne!(first.index, second.index);
+
+        pool.report_failure(&first, &auth_error());
+        assert_eq!(pool.acquire().unwrap().index, second.index);
+        assert_eq!(pool.acquire().unwrap().index, second.index);
+
+        pool.report_failure(&second, &auth_error());
+        assert!(matches!(
+            pool.acquire(),
+            Err(OkLinkError::NoAvailableKey { .. })
+        ));
+
+        let err = pool.acquire().unwrap_err();
+        assert!(
+            err.retry_after()
+                .is_some_and(|d| d <= Duration::from_secs(30))
+        );
+        assert_eq!(pool.available_in(), err.retry_after());
+
+        tokio::time::advance(Duration::from_secs(31)).await;
+        assert_eq!(pool.available_in(), None);
+        assert!(pool.acquire().is_ok());
+    }
+
+    #[test]
+    fn test_least_used_and_usage() {
+        let pool = KeyPool::new(["key-a-0000", "key-b-0000"])
+            .unwrap()
+            .with_selection(KeySelection::LeastUsed)
+            .with_daily_limit(10);
+        for _ in 0..4 {
+            pool.acquire().unwrap();
+        }
+        let usage = pool.usage();
+        assert_eq!(usage[0].requests_today, 2);
+        assert_eq!(usage[1].requests_today, 2);
+        assert_eq!(usage[0].remaining_today, Some(8));
+        assert_eq!(usage[0].key, "key-****0000");
+    }
+
+    #[test]
+    fn test_invalid_key_rejected() {
+        assert!(KeyPool::new(["bad\nkey"]).is_err());
+        assert!(KeyPool::new(Vec::<String>::new()).is_err());
+        assert!(matches!(
+            KeyPool::new(["key-a-0000", ""]),
+            Err(OkLinkError::Config(_))
+        ));
+        assert!(matches!(
+            KeyPool::new(["  \t"]),
+            Err(OkLinkError::Config(_))
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limit_does_not_park() {
+        let pool = KeyPool::new(["key-a-0000"]).unwrap();
+        let lease = pool.acquire().unwrap();
+        pool.report_failure(&lease, &rate_limit_error());
+
+        let usage = pool.usage();
+        assert_eq!(usage[0].failures, 1);
+        assert_eq!(usage[0].parked_for, None);
+        assert!(pool.acquire().is_ok());
+    }
+}
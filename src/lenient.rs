@@ -0,0 +1,200 @@
+//! 宽松的反序列化工具。
+//!
+//! OkLink 几乎所有数字字段都以字符串返回（如 `"height": "812742"`），有时又是 JSON 数字，
+//! 缺失的值则是空字符串。这里的函数同时接受数字和字符串，配合
+//! `#[serde(with = "crate::lenient")]` 或 `#[serde(with = "crate::lenient::option")]` 使用。
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+/// 金额类型。
+///
+/// 启用 `rust_decimal` 特性时为 `rust_decimal::Decimal`，启用 `bigdecimal` 特性时为
+/// `bigdecimal::BigDecimal`，否则保留 OkLink 返回的原始字符串。
+#[cfg(feature = "rust_decimal")]
+pub type Amount = rust_decimal::Decimal;
+#[cfg(all(feature = "bigdecimal", not(feature = "rust_decimal")))]
+pub type Amount = bigdecimal::BigDecimal;
+#[cfg(not(any(feature = "rust_decimal", feature = "bigdecimal")))]
+pub type Amount = String;
+
+/// 时间戳类型。
+///
+/// 启用 `chrono` 特性时为 `chrono::DateTime<Utc>`，否则为毫秒级 Unix 时间戳。
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = u64;
+
+/// 可以从 JSON 字符串或数字宽松解析的类型。
+pub trait Lenient: Sized {
+    /// 从字符串解析。
+    fn from_str_lenient(s: &str) -> Result<Self, String>;
+
+    /// 从 JSON 数字解析。
+    fn from_number(n: &serde_json::Number) -> Result<Self, String> {
+        Self::from_str_lenient(&n.to_string())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Raw {
+    Str(String),
+    Num(serde_json::Number),
+    Null(()),
+}
+
+impl Raw {
+    fn parse<T: Lenient>(self) -> Result<Option<T>, String> {
+        match self {
+            Raw::Str(s) if s.trim().is_empty() => Ok(None),
+            Raw::Str(s) => T::from_str_lenient(s.trim()).map(Some),
+            Raw::Num(n) => T::from_number(&n).map(Some),
+            Raw::Null(()) => Ok(None),
+        }
+    }
+}
+
+/// 反序列化必填字段，空字符串或 `null` 视为错误。
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Lenient,
+{
+    Raw::deserialize(deserializer)?
+        .parse()
+        .map_err(D::Error::custom)?
+        .ok_or_else(|| D::Error::custom("unexpected empty value"))
+}
+
+pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    value.serialize(serializer)
+}
+
+/// 可选字段，空字符串与 `null` 都解析为 `None`。
+pub mod option {
+    use super::*;
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Lenient,
+    {
+        Raw::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+
+    pub fn serialize<S, T>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        value.serialize(serializer)
+    }
+}
+
+macro_rules! lenient_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Lenient for $ty {
+                fn from_str_lenient(s: &str) -> Result<Self, String> {
+                    s.parse().map_err(|e| format!("invalid integer {:?}: {}", s, e))
+                }
+            }
+        )*
+    };
+}
+
+lenient_int!(u32, u64, i64);
+
+impl Lenient for f64 {
+    fn from_str_lenient(s: &str) -> Result<Self, String> {
+        s.parse()
+            .map_err(|e| format!("invalid number {:?}: {}", s, e))
+    }
+}
+
+impl Lenient for String {
+    fn from_str_lenient(s: &str) -> Result<Self, String> {
+        Ok(s.to_string())
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Lenient for rust_decimal::Decimal {
+    fn from_str_lenient(s: &str) -> Result<Self, String> {
+        use std::str::FromStr;
+        rust_decimal::Decimal::from_str(s)
+            .or_else(|_| rust_decimal::Decimal::from_scientific(s))
+            .map_err(|e| format!("invalid decimal {:?}: {}", s, e))
+    }
+}
+
+#[cfg(all(feature = "bigdecimal", not(feature = "rust_decimal")))]
+impl Lenient for bigdecimal::BigDecimal {
+    fn from_str_lenient(s: &str) -> Result<Self, String> {
+        use std::str::FromStr;
+        bigdecimal::BigDecimal::from_str(s).map_err(|e| format!("invalid decimal {:?}: {}", s, e))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Lenient for chrono::DateTime<chrono::Utc> {
+    /// 接受毫秒级时间戳，也接受 RFC 3339 字符串（序列化后的格式）。
+    fn from_str_lenient(s: &str) -> Result<Self, String> {
+        if let Ok(millis) = s.parse::<i64>() {
+            return chrono::DateTime::from_timestamp_millis(millis)
+                .ok_or_else(|| format!("timestamp out of range: {}", millis));
+        }
+        chrono::DateTime::parse_from_rfc3339(s)
+            .map(|t| t.with_timezone(&chrono::Utc))
+            .map_err(|e| format!("invalid timestamp {:?}: {}", s, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Sample {
+        #[serde(with = "crate::lenient")]
+        height: u64,
+        #[serde(with = "crate::lenient::option", default)]
+        index: Option<u64>,
+        #[serde(with = "crate::lenient")]
+        amount: Amount,
+        #[serde(with = "crate::lenient")]
+        time: Timestamp,
+    }
+
+    #[test]
+    fn test_accepts_strings_and_numbers() {
+        let a: Sample = serde_json::from_str(
+            r#"{"height":"812742","index":"","amount":"0.5","time":"1697623175000"}"#,
+        )
+        .unwrap();
+        let b: Sample = serde_json::from_str(
+            r#"{"height":812742,"index":3,"amount":"0.5","time":1697623175000}"#,
+        )
+        .unwrap();
+        assert_eq!(a.height, 812742);
+        assert_eq!(a.height, b.height);
+        assert_eq!(a.index, None);
+        assert_eq!(b.index, Some(3));
+        assert_eq!(a.amount, b.amount);
+        assert_eq!(a.time, b.time);
+    }
+
+    #[test]
+    fn test_rejects_empty_required_field() {
+        let result =
+            serde_json::from_str::<Sample>(r#"{"height":"","amount":"0","time":"1697623175000"}"#);
+        assert!(result.is_err());
+    }
+}
@@ -1,27 +1,73 @@
+pub mod api;
+pub mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod builder;
+pub mod cache;
+pub mod cassette;
+pub mod chain;
+pub mod error;
 pub mod http_client;
+pub mod key_pool;
+pub mod lenient;
+#[cfg(feature = "tower")]
+pub mod middleware;
+pub mod mock;
+pub mod page;
+pub mod params;
+pub mod rate_limit;
 pub mod resp;
+pub mod retry;
+pub mod stream;
+mod telemetry;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod types;
 
-use crate::http_client::HttpClient;
-use reqwest::{
-    Method,
-    header::{HeaderMap, HeaderValue},
+pub use crate::api::OkLinkApi;
+pub use crate::builder::OkLinkClientBuilder;
+pub use crate::chain::{Chain, ChainRegistry};
+pub use crate::error::{OkLinkError, Result};
+pub use crate::page::{PageInfo, Paginated};
+pub use crate::params::EndpointParams;
+use crate::params::{
+    AddressInscriptionListParams, AddressInternalTransactionListParams,
+    AddressNormalTransactionListParams, AddressSummaryParams, AddressTokenTransactionListParams,
+    AddressTransactionListParams, AddressUtxoParams, BalanceMultiParams, BlockFillsParams,
+    BlockHeightByTimeParams, BlockListParams, BlockTransactionListMultiParams,
+    BlockTransactionListParams, BlockchainSummaryParams, Brc20BalanceDetailParams,
+    Brc20BalanceListParams, ChainInfoParams, Closest, InscriptionTransactionListParams,
+    ProtocolType, PublishTxParams, TokenPriceMarketDataParams,
 };
+use crate::{
+    cache::{Cache, CachePolicy},
+    cassette::Cassette,
+    http_client::{ACCESS_KEY_HEADER, HttpClient},
+    key_pool::KeyPool,
+    rate_limit::RateLimiter,
+    retry::RetryPolicy,
+};
+use reqwest::header::{HeaderMap, HeaderValue};
 use resp::{
-    AddressSummary, BalanceMulti, OkLinkBalanceDetail, OkLinkBalancePage, OkLinkResp, PublishTxInfo,
+    AddressSummary, BalanceMulti, OkLinkBalanceDetail, OkLinkBalancePage, OkLinkResp,
+    PublishTxInfo, Response,
 };
-use serde_json::{Value, json};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
 use types::{
-    BlockChainInfo, BlockTransactionList, BlockTransactionListMulti, InscriptionOk, OkApiUri,
-    UtxoList,
+    AddressTransactionList, BlockChainInfo, BlockFills, BlockHeightByTime, BlockList,
+    BlockTransactionList, BlockTransactionListMulti, BlockchainSummary, InscriptionOk,
+    InternalTransactionList, NormalTransactionList, OkApiUri, TokenTransactionList, UtxoList,
 };
 
 /// `OkLinkClient` 是一个用于与 OkLink API 进行交互的客户端结构体。
 /// 它封装了 HTTP 请求逻辑，并提供了多种方法来查询链上数据。
-#[derive(Debug)]
+///
+/// `OkLinkClient` 可以廉价地 clone，clone 后共享同一个连接池、限流器和 Key 池。
+#[derive(Debug, Clone)]
 pub struct OkLinkClient {
     client: HttpClient, // HTTP 客户端实例
-    chain: String,      // 链名称（如 "BTC"）
+    chain: String,      // 链名称（如 "BTC"），为兼容旧接口保留字符串形式
     chain_id: u64,      // 链 ID
 }
 
@@ -36,9 +82,11 @@ impl OkLinkClient {
     ///
     /// # 返回值
     /// 返回一个 `OkLinkClient` 实例。
+    ///
+    /// `api_key` 包含非法字符时会 panic，需要校验输入时请使用 [`OkLinkClient::builder`]。
     pub fn new(base_url: String, api_key: String, chain: String, chain_id: u64) -> Self {
         let mut headers = HeaderMap::new();
-        headers.insert("Ok-Access-Key", HeaderValue::from_str(&api_key).unwrap());
+        headers.insert(ACCESS_KEY_HEADER, HeaderValue::from_str(&api_key).unwrap());
 
         OkLinkClient {
             client: HttpClient::new(base_url, Some(headers)),
@@ -47,25 +95,151 @@ impl OkLinkClient {
         }
     }
 
-    /// 获取链上代币的价格和市场数据。
+    /// 创建一个 `OkLinkClientBuilder`，`build` 时校验配置并返回错误而不是 panic。
+    pub fn builder() -> OkLinkClientBuilder {
+        OkLinkClientBuilder::new()
+    }
+
+    /// 返回一个指向另一条链的客户端，用于按次切换链。
+    ///
+    /// 返回的客户端与当前客户端共享连接池、限流器和 Key 池，例如：
+    /// `client.on_chain(Chain::Eth).get_chain_info().await`。
+    pub fn on_chain(&self, chain: Chain) -> OkLinkClient {
+        OkLinkClient {
+            client: self.client.clone(),
+            chain: chain.short_name().to_string(),
+            chain_id: chain.chain_id(),
+        }
+    }
+
+    /// 当前客户端所在的链，链名称无法识别时返回 `None`。
+    pub fn chain(&self) -> Option<Chain> {
+        self.chain.parse().ok()
+    }
+
+    /// 设置请求失败后的重试策略。
+    ///
+    /// `publish_tx` 默认不会被重试，除非策略开启了 `retry_non_idempotent`。
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.client = self.client.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// 设置客户端限流器，每次请求（包括重试）都会先从限流器获取令牌。
+    ///
+    /// 限流器可以被 clone 后在多个客户端之间共享同一份预算。
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.client = self.client.with_rate_limiter(rate_limiter);
+        self
+    }
+
+    /// 客户端使用的限流器，可以用来查询今日剩余额度，未设置时返回 `None`。
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.client.rate_limiter()
+    }
+
+    /// 使用多个 API Key 轮换发送请求，替代构造时传入的单个 `api_key`。
+    pub fn with_key_pool(mut self, key_pool: KeyPool) -> Self {
+        self.client = self.client.with_key_pool(key_pool);
+        self
+    }
+
+    /// 启用响应缓存，命中缓存的请求不消耗 API 额度。
+    pub fn with_cache<C: Cache + 'static>(mut self, cache: C) -> Self {
+        self.client = self.client.with_cache(cache);
+        self
+    }
+
+    /// 设置各接口的缓存时间与确认数，详见 [`CachePolicy`]。
+    pub fn with_cache_policy(mut self, cache_policy: CachePolicy) -> Self {
+        self.client = self.client.with_cache_policy(cache_policy);
+        self
+    }
+
+    /// 在 `*_with_meta` 方法返回的元数据中保留原始响应体，便于排查解析问题。
+    pub fn with_raw_body(mut self, raw_body: bool) -> Self {
+        self.client = self.client.with_raw_body(raw_body);
+        self
+    }
+
+    /// 挂载录制/回放磁带，用于录制真实响应或离线回放。
+    pub fn with_cassette(mut self, cassette: Cassette) -> Self {
+        self.client = self.client.with_cassette(cassette);
+        self
+    }
+
+    /// 按请求参数调用接口，参数详见 [`params`] 模块。
+    ///
+    /// 参数未指定链时使用客户端当前的链；参数校验失败时返回 `OkLinkError::InvalidRequest`，
+    /// 不会发送请求。
+    ///
+    /// # 参数
+    /// - `params`: 接口的请求参数，如 [`AddressUtxoParams`]。
     ///
     /// # 返回值
-    /// 返回一个 `anyhow::Result<Value>`，包含价格和市场数据的 JSON 响应。
-    pub async fn get_token_price_market_data(&self) -> anyhow::Result<Value> {
-        let response = self
-            .client
-            .request(
-                &format!(
-                    "{}?chainId={}",
-                    OkApiUri::TokenPriceMarketData.as_str(),
-                    self.chain_id
-                ),
-                Method::GET,
-                None,
-                true,
+    /// 返回一个 `Result<P::Output>`，包含接口的响应。
+    pub async fn query<P: EndpointParams>(&self, params: &P) -> Result<P::Output> {
+        self.query_with_meta(params).await.map(Response::into_data)
+    }
+
+    /// 与 [`Self::query`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn query_with_meta<P: EndpointParams>(
+        &self,
+        params: &P,
+    ) -> Result<Response<P::Output>> {
+        self.get_params(params).await
+    }
+
+    async fn get_params<P, T>(&self, params: &P) -> Result<Response<T>>
+    where
+        P: EndpointParams,
+        T: DeserializeOwned,
+    {
+        let uri = params::encode(params, Some(&self.chain))?;
+        self.client.get_with_meta(&uri, true).await
+    }
+
+    /// 按请求参数广播交易，参数未指定链时使用客户端当前的链。
+    ///
+    /// # 参数
+    /// - `params`: 交易广播参数。
+    ///
+    /// # 返回值
+    /// 返回一个 `Result<OkLinkResp<PublishTxInfo>>`，包含交易发布的结果信息。
+    pub async fn publish(&self, params: &PublishTxParams) -> Result<OkLinkResp<PublishTxInfo>> {
+        self.publish_with_meta(params)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::publish`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn publish_with_meta(
+        &self,
+        params: &PublishTxParams,
+    ) -> Result<Response<OkLinkResp<PublishTxInfo>>> {
+        params.validate()?;
+        self.client
+            .post_with_meta(
+                OkApiUri::TransactionPublicshTx.as_str(),
+                &params.clone().or_chain(&self.chain),
             )
-            .await?;
-        Ok(response)
+            .await
+    }
+
+    /// 获取链上代币的价格和市场数据。
+    ///
+    /// # 返回值
+    /// 返回一个 `Result<Value>`，包含价格和市场数据的 JSON 响应。
+    pub async fn get_token_price_market_data(&self) -> Result<Value> {
+        self.get_token_price_market_data_with_meta()
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_token_price_market_data`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_token_price_market_data_with_meta(&self) -> Result<Response<Value>> {
+        self.query_with_meta(&TokenPriceMarketDataParams::new(self.chain_id))
+            .await
     }
 
     /// 获取地址的汇总信息。
@@ -74,26 +248,23 @@ impl OkLinkClient {
     /// - `address`: 要查询的地址。
     ///
     /// # 返回值
-    /// 返回一个 `anyhow::Result<OkLinkResp<AddressSummary>>`，包含地址的汇总信息。
+    /// 返回一个 `Result<OkLinkResp<AddressSummary>>`，包含地址的汇总信息。
     pub async fn get_address_summary_oklink(
         &self,
         address: &str,
-    ) -> anyhow::Result<OkLinkResp<AddressSummary>> {
-        let response = self
-            .client
-            .request(
-                &format!(
-                    "{}?chainShortName={}&address={}",
-                    OkApiUri::AddressSummary.as_str(),
-                    self.chain,
-                    address
-                ),
-                Method::GET,
-                None,
-                true,
-            )
-            .await?;
-        Ok(serde_json::from_value(response)?)
+    ) -> Result<OkLinkResp<AddressSummary>> {
+        self.get_address_summary_oklink_with_meta(address)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_address_summary_oklink`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_address_summary_oklink_with_meta(
+        &self,
+        address: &str,
+    ) -> Result<Response<OkLinkResp<AddressSummary>>> {
+        self.query_with_meta(&AddressSummaryParams::new(address))
+            .await
     }
 
     /// 获取地址的多币种余额信息。
@@ -102,26 +273,174 @@ impl OkLinkClient {
     /// - `address`: 要查询的地址。
     ///
     /// # 返回值
-    /// 返回一个 `anyhow::Result<OkLinkResp<BalanceMulti>>`，包含地址的多币种余额信息。
+    /// 返回一个 `Result<OkLinkResp<BalanceMulti>>`，包含地址的多币种余额信息。
     pub async fn get_address_balance_oklink_multi(
         &self,
         address: &str,
-    ) -> anyhow::Result<OkLinkResp<BalanceMulti>> {
-        let response = self
-            .client
-            .request(
-                &format!(
-                    "{}?chainShortName={}&address={}",
-                    OkApiUri::BalanceMulti.as_str(),
-                    self.chain,
-                    address
-                ),
-                Method::GET,
-                None,
-                true,
-            )
-            .await?;
-        Ok(serde_json::from_value(response)?)
+    ) -> Result<OkLinkResp<BalanceMulti>> {
+        self.get_address_balance_oklink_multi_with_meta(address)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_address_balance_oklink_multi`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_address_balance_oklink_multi_with_meta(
+        &self,
+        address: &str,
+    ) -> Result<Response<OkLinkResp<BalanceMulti>>> {
+        self.query_with_meta(&BalanceMultiParams::new(address))
+            .await
+    }
+
+    /// 获取地址的全部交易，按时间从新到旧排列。
+    ///
+    /// 需要按区块区间、方向等条件过滤时，使用 [`AddressTransactionListParams`] 与 [`Self::query`]。
+    ///
+    /// # 参数
+    /// - `address`: 要查询的地址。
+    /// - `page`: 当前页码。
+    /// - `limit`: 每页的大小。
+    ///
+    /// # 返回值
+    /// 返回一个 `Result<OkLinkResp<AddressTransactionList>>`，包含全部交易分页信息。
+    pub async fn get_address_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<AddressTransactionList>> {
+        self.get_address_transaction_list_with_meta(address, page, limit)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_address_transaction_list`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_address_transaction_list_with_meta(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<AddressTransactionList>>> {
+        self.query_with_meta(
+            &AddressTransactionListParams::new(address)
+                .with_page(page)
+                .with_limit(limit),
+        )
+        .await
+    }
+
+    /// 获取地址的普通交易，按时间从新到旧排列。
+    ///
+    /// 需要按区块区间、方向等条件过滤时，使用 [`AddressNormalTransactionListParams`] 与 [`Self::query`]。
+    ///
+    /// # 参数
+    /// - `address`: 要查询的地址。
+    /// - `page`: 当前页码。
+    /// - `limit`: 每页的大小。
+    ///
+    /// # 返回值
+    /// 返回一个 `Result<OkLinkResp<NormalTransactionList>>`，包含普通交易分页信息。
+    pub async fn get_address_normal_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<NormalTransactionList>> {
+        self.get_address_normal_transaction_list_with_meta(address, page, limit)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_address_normal_transaction_list`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_address_normal_transaction_list_with_meta(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<NormalTransactionList>>> {
+        self.query_with_meta(
+            &AddressNormalTransactionListParams::new(address)
+                .with_page(page)
+                .with_limit(limit),
+        )
+        .await
+    }
+
+    /// 获取地址的内部交易，按时间从新到旧排列。
+    ///
+    /// 需要按区块区间过滤时，使用 [`AddressInternalTransactionListParams`] 与 [`Self::query`]。
+    ///
+    /// # 参数
+    /// - `address`: 要查询的地址。
+    /// - `page`: 当前页码。
+    /// - `limit`: 每页的大小。
+    ///
+    /// # 返回值
+    /// 返回一个 `Result<OkLinkResp<InternalTransactionList>>`，包含内部交易分页信息。
+    pub async fn get_address_internal_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<InternalTransactionList>> {
+        self.get_address_internal_transaction_list_with_meta(address, page, limit)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_address_internal_transaction_list`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_address_internal_transaction_list_with_meta(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<InternalTransactionList>>> {
+        self.query_with_meta(
+            &AddressInternalTransactionListParams::new(address)
+                .with_page(page)
+                .with_limit(limit),
+        )
+        .await
+    }
+
+    /// 获取地址的代币转账，按时间从新到旧排列。
+    ///
+    /// 需要按区块区间、方向等条件过滤时，使用 [`AddressTokenTransactionListParams`] 与 [`Self::query`]。
+    ///
+    /// # 参数
+    /// - `address`: 要查询的地址。
+    /// - `protocol_type`: 代币类型，如 `ProtocolType::Token20`。
+    /// - `page`: 当前页码。
+    /// - `limit`: 每页的大小。
+    ///
+    /// # 返回值
+    /// 返回一个 `Result<OkLinkResp<TokenTransactionList>>`，包含代币转账分页信息。
+    pub async fn get_address_token_transaction_list(
+        &self,
+        address: &str,
+        protocol_type: ProtocolType,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<TokenTransactionList>> {
+        self.get_address_token_transaction_list_with_meta(address, protocol_type, page, limit)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_address_token_transaction_list`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_address_token_transaction_list_with_meta(
+        &self,
+        address: &str,
+        protocol_type: ProtocolType,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<TokenTransactionList>>> {
+        self.query_with_meta(
+            &AddressTokenTransactionListParams::new(address, protocol_type)
+                .with_page(page)
+                .with_limit(limit),
+        )
+        .await
     }
 
     /// 获取 BRC-20 代币的余额分页信息。
@@ -132,29 +451,31 @@ impl OkLinkClient {
     /// - `page_size`: 每页的大小。
     ///
     /// # 返回值
-    /// 返回一个 `anyhow::Result<OkLinkResp<OkLinkBalancePage>>`，包含 BRC-20 代币的余额分页信息。
+    /// 返回一个 `Result<OkLinkResp<OkLinkBalancePage>>`，包含 BRC-20 代币的余额分页信息。
     pub async fn get_brc20_balance_oklink(
         &self,
         address: &str,
         page: usize,
         page_size: usize,
-    ) -> anyhow::Result<OkLinkResp<OkLinkBalancePage>> {
-        let response = self
-            .client
-            .request(
-                &format!(
-                    "{}?address={}&limit={}&page={}",
-                    OkApiUri::BtcAddressBalanceList.as_str(),
-                    address,
-                    page_size,
-                    page
-                ),
-                Method::GET,
-                None,
-                true,
-            )
-            .await?;
-        Ok(serde_json::from_value(response)?)
+    ) -> Result<OkLinkResp<OkLinkBalancePage>> {
+        self.get_brc20_balance_oklink_with_meta(address, page, page_size)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_brc20_balance_oklink`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_brc20_balance_oklink_with_meta(
+        &self,
+        address: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<Response<OkLinkResp<OkLinkBalancePage>>> {
+        self.query_with_meta(
+            &Brc20BalanceListParams::new(address)
+                .with_page(page)
+                .with_limit(page_size),
+        )
+        .await
     }
 
     /// 获取 BRC-20 代币的详细余额信息。
@@ -166,31 +487,33 @@ impl OkLinkClient {
     /// - `page_size`: 每页的大小。
     ///
     /// # 返回值
-    /// 返回一个 `anyhow::Result<OkLinkResp<OkLinkBalanceDetail>>`，包含 BRC-20 代币的详细余额信息。
+    /// 返回一个 `Result<OkLinkResp<OkLinkBalanceDetail>>`，包含 BRC-20 代币的详细余额信息。
     pub async fn get_brc20_token_detail_oklink(
         &self,
         address: &str,
         tick: &str,
         page: usize,
         page_size: usize,
-    ) -> anyhow::Result<OkLinkResp<OkLinkBalanceDetail>> {
-        let response = self
-            .client
-            .request(
-                &format!(
-                    "{}?address={}&token={}&page={}&limit={}",
-                    OkApiUri::BtcAddressBalanceDetail.as_str(),
-                    address,
-                    tick,
-                    page,
-                    page_size
-                ),
-                Method::GET,
-                None,
-                true,
-            )
-            .await?;
-        Ok(serde_json::from_value(response)?)
+    ) -> Result<OkLinkResp<OkLinkBalanceDetail>> {
+        self.get_brc20_token_detail_oklink_with_meta(address, tick, page, page_size)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_brc20_token_detail_oklink`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_brc20_token_detail_oklink_with_meta(
+        &self,
+        address: &str,
+        tick: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<Response<OkLinkResp<OkLinkBalanceDetail>>> {
+        self.query_with_meta(
+            &Brc20BalanceDetailParams::new(address, tick)
+                .with_page(page)
+                .with_limit(page_size),
+        )
+        .await
     }
 
     /// 获取地址的铭文列表。
@@ -201,30 +524,31 @@ impl OkLinkClient {
     /// - `page_size`: 每页的大小。
     ///
     /// # 返回值
-    /// 返回一个 `anyhow::Result<Value>`，包含地址的铭文列表。
+    /// 返回一个 `Result<Value>`，包含地址的铭文列表。
     pub async fn address_inscription_list_oklink(
         &self,
         address: &str,
         page: usize,
         page_size: usize,
-    ) -> anyhow::Result<Value> {
-        let response = self
-            .client
-            .request(
-                &format!(
-                    "{}?chainShortName={}&protocolType=brc20&address={}&page={}&limit={}",
-                    OkApiUri::InscriptionAddressInscriptionList.as_str(),
-                    self.chain,
-                    address,
-                    page,
-                    page_size
-                ),
-                Method::GET,
-                None,
-                true,
-            )
-            .await?;
-        Ok(response)
+    ) -> Result<Value> {
+        self.address_inscription_list_oklink_with_meta(address, page, page_size)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::address_inscription_list_oklink`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn address_inscription_list_oklink_with_meta(
+        &self,
+        address: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<Response<Value>> {
+        self.get_params(
+            &AddressInscriptionListParams::new(address)
+                .with_page(page)
+                .with_limit(page_size),
+        )
+        .await
     }
 
     /// 发布交易。
@@ -233,55 +557,58 @@ impl OkLinkClient {
     /// - `signed_tx`: 已签名的交易数据。
     ///
     /// # 返回值
-    /// 返回一个 `anyhow::Result<PublishTxInfo>`，包含交易发布的结果信息。
-    pub async fn publish_tx(&self, signed_tx: &str) -> anyhow::Result<PublishTxInfo> {
-        let response = self
-            .client
-            .request(
-                OkApiUri::TransactionPublicshTx.as_str(),
-                Method::POST,
-                Some(&json!({
-                    "chainShortName":self.chain,
-                    "signedTx":signed_tx
-                })),
-                true,
-            )
-            .await?;
-        Ok(serde_json::from_value(response)?)
+    /// 返回一个 `Result<PublishTxInfo>`，包含交易发布的结果信息。
+    pub async fn publish_tx(&self, signed_tx: &str) -> Result<PublishTxInfo> {
+        let resp = self.publish_tx_with_meta(signed_tx).await?.into_data();
+        resp.data.into_iter().next().ok_or(OkLinkError::Api {
+            code: resp.code,
+            msg: "publish tx response contains no transaction".to_string(),
+        })
+    }
+
+    /// 与 [`Self::publish_tx`] 相同，同时返回状态码、响应头与耗时等元数据，
+    /// 以及完整的 OkLink 响应。
+    pub async fn publish_tx_with_meta(
+        &self,
+        signed_tx: &str,
+    ) -> Result<Response<OkLinkResp<PublishTxInfo>>> {
+        self.publish_with_meta(&PublishTxParams::new(signed_tx))
+            .await
     }
 
     /// 获取地址的 UTXO 列表。
     ///
     /// # 参数
     /// - `address`: 要查询的地址。
-    /// - `cursor`: 当前游标位置。
-    /// - `size`: 每次请求的大小。
+    /// - `page`: 页码，从 1 开始。
+    /// - `limit`: 每页的大小。
     ///
     /// # 返回值
-    /// 返回一个 `anyhow::Result<OkLinkResp<UtxoList>>`，包含地址的 UTXO 列表。
+    /// 返回一个 `Result<OkLinkResp<UtxoList>>`，包含地址的 UTXO 列表。
     pub async fn get_btc_utxo_oklink(
         &self,
         address: &str,
-        cursor: usize,
-        size: usize,
-    ) -> anyhow::Result<OkLinkResp<UtxoList>> {
-        let response = self
-            .client
-            .request(
-                &format!(
-                    "{}?chainShortName={}&address={}&page={}&limit={}",
-                    OkApiUri::AddressUtxo.as_str(),
-                    self.chain,
-                    address,
-                    cursor,
-                    size
-                ),
-                Method::GET,
-                None,
-                true,
-            )
-            .await?;
-        Ok(serde_json::from_value(response)?)
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<UtxoList>> {
+        self.get_btc_utxo_oklink_with_meta(address, page, limit)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_btc_utxo_oklink`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_btc_utxo_oklink_with_meta(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<UtxoList>>> {
+        self.query_with_meta(
+            &AddressUtxoParams::new(address)
+                .with_page(page)
+                .with_limit(limit),
+        )
+        .await
     }
 
     /// 根据铭文 ID 获取 UTXO 信息。
@@ -291,27 +618,29 @@ impl OkLinkClient {
     /// - `inscription_number`: 铭文编号。
     ///
     /// # 返回值
-    /// 返回一个 `anyhow::Result<OkLinkResp<InscriptionOk>>`，包含铭文对应的 UTXO 信息。
+    /// 返回一个 `Result<OkLinkResp<InscriptionOk>>`，包含铭文对应的 UTXO 信息。
     pub async fn get_utxo_by_inscription_id_oklink(
         &self,
         inscription_id: &str,
         inscription_number: &str,
-    ) -> anyhow::Result<OkLinkResp<InscriptionOk>> {
-        let response = self
-            .client
-            .request(
-                &format!(
-                    "{}?inscriptionId={}&inscriptionNumber={}",
-                    OkApiUri::BrcTransactionList.as_str(),
-                    inscription_id,
-                    inscription_number
-                ),
-                Method::GET,
-                None,
-                true,
-            )
-            .await?;
-        Ok(serde_json::from_value(response)?)
+    ) -> Result<OkLinkResp<InscriptionOk>> {
+        self.get_utxo_by_inscription_id_oklink_with_meta(inscription_id, inscription_number)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_utxo_by_inscription_id_oklink`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_utxo_by_inscription_id_oklink_with_meta(
+        &self,
+        inscription_id: &str,
+        inscription_number: &str,
+    ) -> Result<Response<OkLinkResp<InscriptionOk>>> {
+        self.query_with_meta(
+            &InscriptionTransactionListParams::new()
+                .with_inscription_id(inscription_id)
+                .with_inscription_number(inscription_number),
+        )
+        .await
     }
 
     // 查询指定区块交易列表
@@ -321,26 +650,25 @@ impl OkLinkClient {
         height: usize,
         page: usize,
         limit: usize,
-    ) -> anyhow::Result<OkLinkResp<BlockTransactionList>> {
-        // chainShortName=eth&startBlockHeight=18809970&endBlockHeight=18809972&limit=1
-        let response = self
-            .client
-            .request(
-                &format!(
-                    "{}?chainShortName={}&height={}&limit={}&page={}",
-                    OkApiUri::BlockTransaction.as_str(),
-                    self.chain,
-                    height,
-                    limit,
-                    page
-                ),
-                Method::GET,
-                None,
-                true,
-            )
-            .await?;
-        println!("{}", response);
-        Ok(serde_json::from_value(response)?)
+    ) -> Result<OkLinkResp<BlockTransactionList>> {
+        self.get_btc_transaction_list_oklink_with_meta(height, page, limit)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_btc_transaction_list_oklink`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_btc_transaction_list_oklink_with_meta(
+        &self,
+        height: usize,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<BlockTransactionList>>> {
+        self.query_with_meta(
+            &BlockTransactionListParams::new(height as u64)
+                .with_page(page)
+                .with_limit(limit),
+        )
+        .await
     }
     // 查询from,to区块交易列表
     pub async fn get_btc_transaction_list_multi_oklink(
@@ -349,44 +677,165 @@ impl OkLinkClient {
         to_block: usize,
         page: usize,
         limit: usize,
-    ) -> anyhow::Result<OkLinkResp<BlockTransactionListMulti>> {
-        // chainShortName=eth&startBlockHeight=18809970&endBlockHeight=18809972&limit=1
-        let response = self
-            .client
-            .request(
-                &format!(
-                    "{}?chainShortName={}&startBlockHeight={}&endBlockHeight={}&limit={}&page={}",
-                    OkApiUri::BlockTransactionMulti.as_str(),
-                    self.chain,
-                    from_block,
-                    to_block,
-                    limit,
-                    page
-                ),
-                Method::GET,
-                None,
-                true,
-            )
-            .await?;
-        Ok(serde_json::from_value(response)?)
+    ) -> Result<OkLinkResp<BlockTransactionListMulti>> {
+        self.get_btc_transaction_list_multi_oklink_with_meta(from_block, to_block, page, limit)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_btc_transaction_list_multi_oklink`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_btc_transaction_list_multi_oklink_with_meta(
+        &self,
+        from_block: usize,
+        to_block: usize,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<BlockTransactionListMulti>>> {
+        self.query_with_meta(
+            &BlockTransactionListMultiParams::new(from_block as u64, to_block as u64)
+                .with_page(page)
+                .with_limit(limit),
+        )
+        .await
+    }
+
+    /// 查询区块详情
+    ///
+    /// # 参数
+    /// - `height`: 区块高度
+    ///
+    /// # 返回值
+    /// 区块哈希、出块者、大小、交易数、奖励、手续费、默克尔根、时间与确认数等信息
+    pub async fn get_block_fills(&self, height: usize) -> Result<OkLinkResp<BlockFills>> {
+        self.get_block_fills_with_meta(height)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_block_fills`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_block_fills_with_meta(
+        &self,
+        height: usize,
+    ) -> Result<Response<OkLinkResp<BlockFills>>> {
+        self.query_with_meta(&BlockFillsParams::new(height as u64))
+            .await
+    }
+
+    /// 查询区块列表，区块按高度从新到旧返回
+    ///
+    /// # 参数
+    /// - `page`: 页码，从 1 开始
+    /// - `limit`: 每页的大小，最大为 100
+    pub async fn get_block_list(&self, page: usize, limit: usize) -> Result<OkLinkResp<BlockList>> {
+        self.get_block_list_with_meta(page, limit)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_block_list`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_block_list_with_meta(
+        &self,
+        page: usize,
+        limit: usize,
+    ) -> Result<Response<OkLinkResp<BlockList>>> {
+        self.query_with_meta(&BlockListParams::new().with_page(page).with_limit(limit))
+            .await
+    }
+
+    /// 按时间查询区块高度
+    ///
+    /// # 参数
+    /// - `time`: Unix 毫秒时间戳
+    /// - `closest`: 取该时间之前还是之后最近的区块
+    pub async fn get_block_height_by_time(
+        &self,
+        time: u64,
+        closest: Closest,
+    ) -> Result<OkLinkResp<BlockHeightByTime>> {
+        self.get_block_height_by_time_with_meta(time, closest)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_block_height_by_time`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_block_height_by_time_with_meta(
+        &self,
+        time: u64,
+        closest: Closest,
+    ) -> Result<Response<OkLinkResp<BlockHeightByTime>>> {
+        self.query_with_meta(&BlockHeightByTimeParams::new(time).with_closest(closest))
+            .await
+    }
+
+    /// 将时间区间转换为当前链上的区块高度区间
+    ///
+    /// 起始高度为不早于 `start_time` 的第一个区块，结束高度为不晚于 `end_time` 的最后一个区块，
+    /// 可以直接用于 [`Self::get_btc_transaction_list_multi_oklink`]。
+    ///
+    /// # 参数
+    /// - `start_time`: 起始时间（含），Unix 毫秒时间戳
+    /// - `end_time`: 结束时间（含），Unix 毫秒时间戳
+    ///
+    /// # 返回值
+    /// `(start_height, end_height)`；区间内没有区块时返回 `OkLinkError::InvalidRequest`
+    pub async fn block_range_by_time(&self, start_time: u64, end_time: u64) -> Result<(u64, u64)> {
+        if start_time > end_time {
+            return Err(OkLinkError::InvalidRequest(format!(
+                "time range start {} is greater than end {}",
+                start_time, end_time
+            )));
+        }
+        let (start, end) = futures::try_join!(
+            self.get_block_height_by_time(start_time, Closest::After),
+            self.get_block_height_by_time(end_time, Closest::Before),
+        )?;
+        let no_block = || {
+            OkLinkError::InvalidRequest(format!(
+                "no block between {} and {} on {}",
+                start_time, end_time, self.chain
+            ))
+        };
+        let start = start.data.first().ok_or_else(no_block)?.height;
+        let end = end.data.first().ok_or_else(no_block)?.height;
+        if start > end {
+            return Err(no_block());
+        }
+        Ok((start, end))
     }
 
     // 查询区块Info
-    pub async fn get_chain_info(&self) -> anyhow::Result<OkLinkResp<BlockChainInfo>> {
-        let response = self
-            .client
-            .request(
-                &format!(
-                    "{}?chainShortName={}",
-                    OkApiUri::ChainInfo.as_str(),
-                    self.chain
-                ),
-                Method::GET,
-                None,
-                true,
-            )
-            .await?;
-        Ok(serde_json::from_value(response)?)
+    pub async fn get_chain_info(&self) -> Result<OkLinkResp<BlockChainInfo>> {
+        self.get_chain_info_with_meta()
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_chain_info`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_chain_info_with_meta(&self) -> Result<Response<OkLinkResp<BlockChainInfo>>> {
+        self.query_with_meta(&ChainInfoParams::new()).await
+    }
+
+    /// 查询 OkLink 支持的全部链的概况
+    ///
+    /// # 返回值
+    /// 每条链的名称、原生代币、最新高度、流通量与交易总数
+    pub async fn get_blockchain_summary(&self) -> Result<OkLinkResp<BlockchainSummary>> {
+        self.get_blockchain_summary_with_meta()
+            .await
+            .map(Response::into_data)
+    }
+
+    /// 与 [`Self::get_blockchain_summary`] 相同，同时返回状态码、响应头与耗时等元数据。
+    pub async fn get_blockchain_summary_with_meta(
+        &self,
+    ) -> Result<Response<OkLinkResp<BlockchainSummary>>> {
+        self.query_with_meta(&BlockchainSummaryParams::new()).await
+    }
+
+    /// 由 `/blockchain/summary` 构建 OkLink 支持的链的列表，见 [`ChainRegistry`]。
+    pub async fn chain_registry(&self) -> Result<ChainRegistry> {
+        let summary = self.get_blockchain_summary().await?;
+        Ok(ChainRegistry::from_summaries(summary.data))
     }
 }
 
@@ -0,0 +1,576 @@
+//! `tower` 中间件集成。
+//!
+//! `HttpClient` 可以使用任意 `tower::Service<http::Request<Bytes>>` 作为传输层，
+//! 从而复用已有的超时、并发限制、熔断等中间件。本模块还把 SDK 自身的重试、限流、
+//! 缓存与追踪策略封装成 `Layer`，可以与其他中间件自由组合：
+//!
+//! ```ignore
+//! let service = ServiceBuilder::new()
+//!     .layer(TraceLayer::new())
+//!     .layer(RetryLayer::new(RetryPolicy::default()))
+//!     .layer(CacheLayer::new(LruCache::new(1000), CachePolicy::default()))
+//!     .layer(RateLimitLayer::new(RateLimiter::per_second(5)))
+//!     .timeout(Duration::from_secs(10))
+//!     .service(ReqwestService::new(reqwest::Client::new()));
+//! // 设置 service 后客户端自身不再重试，重试由 RetryLayer 完成。
+//! let client = OkLinkClient::builder()
+//!     .api_key(api_key)
+//!     .service(service)
+//!     .build()?;
+//! ```
+//!
+//! 需要启用 `tower` 特性。
+
+use crate::{
+    cache::{Cache, CachePolicy},
+    error::{OkLinkError, Result},
+    http_client::{ACCESS_KEY_HEADER, RawResponse, decode_body},
+    rate_limit::RateLimiter,
+    retry::{RetryPolicy, parse_retry_after},
+    telemetry::RequestMeta,
+    types::OkApiUri,
+};
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use reqwest::{
+    Method,
+    header::{CONTENT_TYPE, HeaderValue, RETRY_AFTER},
+};
+use serde::de::IgnoredAny;
+use serde_json::Value;
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tokio::time::Instant;
+use tower::{Layer, Service, ServiceExt, util::BoxCloneSyncService};
+use tracing::Instrument;
+
+/// 中间件使用的错误类型。
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// 传输层的请求类型。
+pub type HttpRequest = http::Request<Bytes>;
+
+/// 传输层的响应类型。
+pub type HttpResponse = http::Response<Bytes>;
+
+pub(crate) type BoxService = BoxCloneSyncService<HttpRequest, HttpResponse, BoxError>;
+
+/// 当前是第几次尝试，由 `RetryLayer` 写入请求的 extensions，供 `TraceLayer` 读取。
+#[derive(Debug, Clone, Copy)]
+struct Attempt(u32);
+
+/// 将任意满足条件的 service 装箱。
+pub(crate) fn boxed<S>(service: S) -> BoxService
+where
+    S: Service<HttpRequest, Response = HttpResponse> + Clone + Send + Sync + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+{
+    BoxCloneSyncService::new(service.map_err(Into::into))
+}
+
+/// 通过 service 发送 `reqwest` 请求。
+pub(crate) async fn call(
+    mut service: BoxService,
+    request: reqwest::Request,
+) -> Result<RawResponse> {
+    let mut http_request = http::Request::builder()
+        .method(request.method().clone())
+        .uri(request.url().as_str())
+        .body(
+            request
+                .body()
+                .and_then(|b| b.as_bytes())
+                .map(Bytes::copy_from_slice)
+                .unwrap_or_default(),
+        )
+        .map_err(|e| OkLinkError::InvalidRequest(e.to_string()))?;
+    *http_request.headers_mut() = request.headers().clone();
+
+    let response = service
+        .ready()
+        .await
+        .map_err(from_box_error)?
+        .call(http_request)
+        .await
+        .map_err(from_box_error)?;
+    let (parts, body) = response.into_parts();
+    Ok(RawResponse {
+        status: parts.status,
+        headers: parts.headers,
+        body: body.to_vec(),
+    })
+}
+
+/// 还原中间件返回的错误：SDK 自身的错误原样返回，其他错误包装为 `OkLinkError::Service`。
+pub fn from_box_error(err: BoxError) -> OkLinkError {
+    match err.downcast::<OkLinkError>() {
+        Ok(err) => *err,
+        Err(err) => match err.downcast::<reqwest::Error>() {
+            Ok(err) => OkLinkError::Transport(*err),
+            Err(err) => OkLinkError::Service(err),
+        },
+    }
+}
+
+fn retry_after_header(response: &HttpResponse) -> Option<String> {
+    response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+/// 按 SDK 的规则检查响应，返回其中的错误（HTTP 状态码、业务码、限流）。
+fn response_error(response: &HttpResponse) -> Option<OkLinkError> {
+    let retry_after = retry_after_header(response)
+        .as_deref()
+        .and_then(parse_retry_after);
+    decode_body::<IgnoredAny>(response.status(), retry_after, response.body()).err()
+}
+
+/// 请求相对于 API 基础地址的路径与查询参数，如 `/blockchain/info?chainShortName=btc`。
+///
+/// 与 `HttpClient` 内部使用的缓存键一致，无法识别的接口返回完整路径。
+fn relative_uri(uri: &http::Uri) -> String {
+    let path = uri.path();
+    let path = OkApiUri::ALL
+        .iter()
+        .map(|u| u.as_str())
+        .find(|endpoint| path.ends_with(endpoint))
+        .unwrap_or(path);
+    match uri.query() {
+        Some(query) => format!("{}?{}", path, query),
+        None => path.to_string(),
+    }
+}
+
+/// 使用 `reqwest::Client` 发送请求的 service，通常位于中间件栈的最内层。
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestService {
+    client: reqwest::Client,
+}
+
+impl ReqwestService {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Service<HttpRequest> for ReqwestService {
+    type Response = HttpResponse;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, std::result::Result<HttpResponse, BoxError>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<std::result::Result<(), BoxError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let client = self.client.clone();
+        Box::pin(async move {
+            let request = reqwest::Request::try_from(request.map(reqwest::Body::from))?;
+            let response = client.execute(request).await?;
+            let mut builder = http::Response::builder().status(response.status());
+            if let Some(headers) = builder.headers_mut() {
+                *headers = response.headers().clone();
+            }
+            let body = response.bytes().await?;
+            Ok(builder.body(body)?)
+        })
+    }
+}
+
+/// 为每个请求创建 `oklink.request` span，记录接口、链、状态码、错误码与耗时。
+#[derive(Debug, Clone, Default)]
+pub struct TraceLayer;
+
+impl TraceLayer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for TraceLayer {
+    type Service = TraceService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TraceService { inner }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TraceService<S> {
+    inner: S,
+}
+
+impl<S> Service<HttpRequest> for TraceService<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse> + Clone + Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+{
+    type Response = HttpResponse;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, std::result::Result<HttpResponse, BoxError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<std::result::Result<(), BoxError>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let method = if request.method() == Method::POST {
+            "POST"
+        } else {
+            "GET"
+        };
+        let body = serde_json::from_slice::<Value>(request.body()).ok();
+        let meta = RequestMeta::new(method, &relative_uri(request.uri()), body.as_ref());
+        let attempt = request.extensions().get::<Attempt>().map_or(1, |a| a.0);
+        let api_key = request
+            .headers()
+            .get(ACCESS_KEY_HEADER)
+            .and_then(|v| v.to_str().ok());
+        let span = meta.span(attempt, api_key);
+
+        Box::pin(async move {
+            let started = Instant::now();
+            let result = inner
+                .call(request)
+                .instrument(span.clone())
+                .await
+                .map_err(Into::into);
+            let outcome = match &result {
+                Ok(response) => response_error(response).map_or(Ok(()), Err),
+                Err(err) => Err(OkLinkError::Service(err.to_string().into())),
+            };
+            meta.record(&span, started.elapsed(), true, &outcome);
+            result
+        })
+    }
+}
+
+/// 按照 [`RetryPolicy`] 重试失败的请求，POST 默认不重试。
+#[derive(Debug, Clone, Default)]
+pub struct RetryLayer {
+    policy: RetryPolicy,
+}
+
+impl RetryLayer {
+    pub fn new(policy: RetryPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl<S> Layer<S> for RetryLayer {
+    type Service = RetryService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RetryService {
+            inner,
+            policy: self.policy.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RetryService<S> {
+    inner: S,
+    policy: RetryPolicy,
+}
+
+impl<S> Service<HttpRequest> for RetryService<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse> + Clone + Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+{
+    type Response = HttpResponse;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, std::result::Result<HttpResponse, BoxError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<std::result::Result<(), BoxError>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let policy = self.policy.clone();
+
+        Box::pin(async move {
+            let idempotent = request.method() != Method::POST;
+            let max_attempts = policy.max_attempts_for(idempotent);
+            let (parts, body) = request.into_parts();
+            let mut attempt = 1;
+            loop {
+                let mut request = http::Request::from_parts(parts.clone(), body.clone());
+                request.extensions_mut().insert(Attempt(attempt));
+
+                let result = match inner.ready().await.map_err(Into::into) {
+                    Ok(service) => service.call(request).await.map_err(Into::into),
+                    Err(err) => Err(err),
+                };
+                let err = match &result {
+                    Ok(response) => response_error(response),
+                    Err(_) => None,
+                };
+                let err = match (result, err) {
+                    (Ok(response), None) => return Ok(response),
+                    (Ok(response), Some(err)) => {
+                        if attempt >= max_attempts || !policy.is_retryable(&err) {
+                            return Ok(response);
+                        }
+                        err
+                    }
+                    (Err(err), _) => {
+                        let err = from_box_error(err);
+                        if attempt >= max_attempts || !policy.is_retryable(&err) {
+                            return Err(Box::new(err) as BoxError);
+                        }
+                        err
+                    }
+                };
+                tokio::time::sleep(policy.delay_for(attempt, &err)).await;
+                attempt += 1;
+            }
+        })
+    }
+}
+
+/// 发送请求前先从 [`RateLimiter`] 获取令牌。
+#[derive(Debug, Clone)]
+pub struct RateLimitLayer {
+    rate_limiter: RateLimiter,
+}
+
+impl RateLimitLayer {
+    pub fn new(rate_limiter: RateLimiter) -> Self {
+        Self { rate_limiter }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            rate_limiter: self.rate_limiter.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    rate_limiter: RateLimiter,
+}
+
+impl<S> Service<HttpRequest> for RateLimitService<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse> + Clone + Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+{
+    type Response = HttpResponse;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, std::result::Result<HttpResponse, BoxError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<std::result::Result<(), BoxError>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let rate_limiter = self.rate_limiter.clone();
+
+        Box::pin(async move {
+            rate_limiter.acquire().await;
+            inner.ready().await.map_err(Into::into)?;
+            inner.call(request).await.map_err(Into::into)
+        })
+    }
+}
+
+/// 缓存 GET 请求的成功响应，缓存时间由 [`CachePolicy`] 决定。
+#[derive(Debug, Clone)]
+pub struct CacheLayer {
+    cache: Arc<dyn Cache>,
+    policy: CachePolicy,
+}
+
+impl CacheLayer {
+    pub fn new<C: Cache + 'static>(cache: C, policy: CachePolicy) -> Self {
+        Self {
+            cache: Arc::new(cache),
+            policy,
+        }
+    }
+}
+
+impl<S> Layer<S> for CacheLayer {
+    type Service = CacheService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CacheService {
+            inner,
+            cache: self.cache.clone(),
+            policy: self.policy.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CacheService<S> {
+    inner: S,
+    cache: Arc<dyn Cache>,
+    policy: CachePolicy,
+}
+
+impl<S> Service<HttpRequest> for CacheService<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse> + Clone + Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+{
+    type Response = HttpResponse;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, std::result::Result<HttpResponse, BoxError>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<std::result::Result<(), BoxError>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let cache = self.cache.clone();
+        let policy = self.policy.clone();
+
+        Box::pin(async move {
+            if request.method() != Method::GET {
+                return inner.call(request).await.map_err(Into::into);
+            }
+
+            let key = relative_uri(request.uri());
+            if let Some(body) = cache.get(&key) {
+                let mut response = http::Response::new(Bytes::from(body));
+                response
+                    .headers_mut()
+                    .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                return Ok(response);
+            }
+
+            let response = inner.call(request).await.map_err(Into::into)?;
+            if response_error(&response).is_none()
+                && let Ok(value) = serde_json::from_slice::<Value>(response.body())
+            {
+                policy.observe(&key, &value);
+                if let Some(expiry) = policy.expiry_for(&key, &value) {
+                    cache.put(&key, response.body().to_vec(), expiry);
+                }
+            }
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        OkLinkClient,
+        cache::LruCache,
+        testing::{Fault, OkLinkTestServer},
+    };
+    use std::time::Duration;
+    use tower::{ServiceBuilder, service_fn};
+
+    #[tokio::test]
+    async fn test_layers_compose() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let service = ServiceBuilder::new()
+            .layer(TraceLayer::new())
+            .layer(RetryLayer::new(
+                RetryPolicy::default().with_base_delay(Duration::from_millis(1)),
+            ))
+            .layer(CacheLayer::new(LruCache::new(100), CachePolicy::default()))
+            .layer(RateLimitLayer::new(RateLimiter::per_second(100)))
+            .service(ReqwestService::default());
+        let client = server.client_builder().service(service).build().unwrap();
+
+        server.push_fault(OkApiUri::ChainInfo, Fault::Status(503));
+        client.get_chain_info().await.unwrap();
+        client.get_chain_info().await.unwrap();
+        // 第一次失败后由 RetryLayer 重试，第二次调用命中 CacheLayer。
+        assert_eq!(server.requests_to(OkApiUri::ChainInfo).len(), 2);
+
+        server.push_fault(OkApiUri::TransactionPublicshTx, Fault::Status(503));
+        let err = client.publish_tx("0200").await.err().unwrap();
+        assert_eq!(err.status(), Some(reqwest::StatusCode::SERVICE_UNAVAILABLE));
+        assert_eq!(server.requests_to(OkApiUri::TransactionPublicshTx).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_service_disables_builtin_retry() {
+        let server = OkLinkTestServer::start().await.unwrap();
+        let service = ServiceBuilder::new()
+            .layer(RetryLayer::new(
+                RetryPolicy::default().with_base_delay(Duration::from_millis(1)),
+            ))
+            .service(ReqwestService::default());
+        let client = server.client_builder().service(service).build().unwrap();
+
+        for _ in 0..4 {
+            server.push_fault(OkApiUri::ChainInfo, Fault::Status(503));
+        }
+        // 只有 RetryLayer 的 3 次尝试，客户端自身不再嵌套重试。
+        client.get_chain_info().await.unwrap_err();
+        assert_eq!(server.requests_to(OkApiUri::ChainInfo).len(), 3);
+
+        // 显式设置的重试策略仍然生效。
+        let client = server
+            .client_builder()
+            .service(ReqwestService::default())
+            .retry_policy(RetryPolicy::default().with_base_delay(Duration::from_millis(1)))
+            .build()
+            .unwrap();
+        client.get_chain_info().await.unwrap();
+        assert_eq!(server.requests_to(OkApiUri::ChainInfo).len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_custom_service() {
+        let service = service_fn(|request: HttpRequest| async move {
+            assert_eq!(request.uri().path(), "/api/v5/explorer/blockchain/info");
+            let body = crate::testing::default_fixture(OkApiUri::ChainInfo).to_string();
+            Ok::<_, BoxError>(http::Response::new(Bytes::from(body)))
+        });
+        let client = OkLinkClient::builder()
+            .api_key("key")
+            .service(service)
+            .build()
+            .unwrap();
+
+        let info = client.get_chain_info().await.unwrap();
+        assert_eq!(info.data[0].last_height, 812742);
+
+        let failing = service_fn(|_: HttpRequest| async {
+            Err::<HttpResponse, BoxError>("upstream overloaded".into())
+        });
+        let client = OkLinkClient::builder()
+            .api_key("key")
+            .service(failing)
+            .build()
+            .unwrap();
+        let err = client.get_chain_info().await.unwrap_err();
+        assert!(matches!(err, OkLinkError::Service(_)));
+    }
+}
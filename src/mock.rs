@@ -0,0 +1,451 @@
+use crate::{
+    api::OkLinkApi,
+    batch::ByAddress,
+    error::{OkLinkError, Result},
+    params::{Closest, ProtocolType},
+    resp::{
+        AddressSummary, BalanceMulti, OkLinkBalanceDetail, OkLinkBalancePage, OkLinkResp,
+        PublishTxInfo,
+    },
+    types::{
+        AddressTransactionList, BlockChainInfo, BlockFills, BlockHeightByTime, BlockList,
+        BlockTransactionList, BlockTransactionListMulti, BlockchainSummary, InscriptionOk,
+        InternalTransaction, InternalTransactionList, NormalTransaction, NormalTransactionList,
+        TokenTransaction, TokenTransactionList, UtxoList,
+    },
+};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+};
+
+/// `MockOkLinkApi` 收到的一次调用。
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockCall {
+    /// 方法名，与 `OkLinkApi` 上的方法同名
+    pub method: &'static str,
+    /// 调用参数
+    pub args: Value,
+}
+
+/// `MockOkLinkApi` 是 `OkLinkApi` 的内存实现，用于离线单元测试。
+///
+/// 按方法名预先放入响应，调用时按先进先出的顺序返回；队列为空时返回通过
+/// `set_ok` 设置的默认响应，仍然没有时返回 `OkLinkError::InvalidRequest`。
+/// 每次调用都会被记录下来，可以通过 `calls` 检查。
+///
+/// ```ignore
+/// let mock = MockOkLinkApi::new();
+/// mock.push_ok("get_chain_info", &resp);
+/// let info = mock.get_chain_info().await?;
+/// assert_eq!(mock.calls_to("get_chain_info").len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct MockOkLinkApi {
+    queued: Mutex<HashMap<&'static str, VecDeque<Result<Value>>>>,
+    defaults: Mutex<HashMap<&'static str, Value>>,
+    calls: Mutex<Vec<MockCall>>,
+}
+
+impl MockOkLinkApi {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 为 `method` 追加一个成功响应，只会被使用一次。
+    pub fn push_ok<T: Serialize>(&self, method: &'static str, response: &T) -> &Self {
+        let value = serde_json::to_value(response).expect("mock response must serialize");
+        self.queue(method, Ok(value))
+    }
+
+    /// 为 `method` 追加一个错误响应，只会被使用一次。
+    pub fn push_err(&self, method: &'static str, err: OkLinkError) -> &Self {
+        self.queue(method, Err(err))
+    }
+
+    /// 设置 `method` 的默认响应，队列为空时每次调用都返回它。
+    pub fn set_ok<T: Serialize>(&self, method: &'static str, response: &T) -> &Self {
+        let value = serde_json::to_value(response).expect("mock response must serialize");
+        self.defaults.lock().unwrap().insert(method, value);
+        self
+    }
+
+    /// 返回所有已记录的调用。
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// 返回某个方法的全部调用。
+    pub fn calls_to(&self, method: &str) -> Vec<MockCall> {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| c.method == method)
+            .cloned()
+            .collect()
+    }
+
+    fn queue(&self, method: &'static str, response: Result<Value>) -> &Self {
+        self.queued
+            .lock()
+            .unwrap()
+            .entry(method)
+            .or_default()
+            .push_back(response);
+        self
+    }
+
+    fn respond<T: DeserializeOwned>(&self, method: &'static str, args: Value) -> Result<T> {
+        self.calls.lock().unwrap().push(MockCall { method, args });
+
+        let queued = self
+            .queued
+            .lock()
+            .unwrap()
+            .get_mut(method)
+            .and_then(VecDeque::pop_front);
+        let value = match queued {
+            Some(response) => response?,
+            None => self
+                .defaults
+                .lock()
+                .unwrap()
+                .get(method)
+                .cloned()
+                .ok_or_else(|| {
+                    OkLinkError::InvalidRequest(format!("no mock response for {}", method))
+                })?,
+        };
+
+        serde_json::from_value(value.clone()).map_err(|source| OkLinkError::Decode {
+            source,
+            body: value.to_string(),
+        })
+    }
+}
+
+impl OkLinkApi for MockOkLinkApi {
+    async fn get_token_price_market_data(&self) -> Result<Value> {
+        self.respond("get_token_price_market_data", json!({}))
+    }
+
+    async fn get_address_summary_oklink(
+        &self,
+        address: &str,
+    ) -> Result<OkLinkResp<AddressSummary>> {
+        self.respond("get_address_summary_oklink", json!({ "address": address }))
+    }
+
+    async fn get_address_balance_oklink_multi(
+        &self,
+        address: &str,
+    ) -> Result<OkLinkResp<BalanceMulti>> {
+        self.respond(
+            "get_address_balance_oklink_multi",
+            json!({ "address": address }),
+        )
+    }
+
+    async fn get_address_balance_batch(&self, addresses: &[&str]) -> Result<BalanceMulti> {
+        self.respond(
+            "get_address_balance_batch",
+            json!({ "addresses": addresses }),
+        )
+    }
+
+    async fn get_address_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<NormalTransaction>> {
+        self.respond(
+            "get_address_transaction_list_batch",
+            json!({
+                "addresses": addresses,
+                "start_block_height": start_block_height,
+                "end_block_height": end_block_height,
+            }),
+        )
+    }
+
+    async fn get_address_normal_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<NormalTransaction>> {
+        self.respond(
+            "get_address_normal_transaction_list_batch",
+            json!({
+                "addresses": addresses,
+                "start_block_height": start_block_height,
+                "end_block_height": end_block_height,
+            }),
+        )
+    }
+
+    async fn get_address_internal_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<InternalTransaction>> {
+        self.respond(
+            "get_address_internal_transaction_list_batch",
+            json!({
+                "addresses": addresses,
+                "start_block_height": start_block_height,
+                "end_block_height": end_block_height,
+            }),
+        )
+    }
+
+    async fn get_address_token_transaction_list_batch(
+        &self,
+        addresses: &[&str],
+        protocol_type: ProtocolType,
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Result<ByAddress<TokenTransaction>> {
+        self.respond(
+            "get_address_token_transaction_list_batch",
+            json!({
+                "addresses": addresses,
+                "protocol_type": protocol_type,
+                "start_block_height": start_block_height,
+                "end_block_height": end_block_height,
+            }),
+        )
+    }
+
+    async fn get_address_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<AddressTransactionList>> {
+        self.respond(
+            "get_address_transaction_list",
+            json!({ "address": address, "page": page, "limit": limit }),
+        )
+    }
+
+    async fn get_address_normal_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<NormalTransactionList>> {
+        self.respond(
+            "get_address_normal_transaction_list",
+            json!({ "address": address, "page": page, "limit": limit }),
+        )
+    }
+
+    async fn get_address_internal_transaction_list(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<InternalTransactionList>> {
+        self.respond(
+            "get_address_internal_transaction_list",
+            json!({ "address": address, "page": page, "limit": limit }),
+        )
+    }
+
+    async fn get_address_token_transaction_list(
+        &self,
+        address: &str,
+        protocol_type: ProtocolType,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<TokenTransactionList>> {
+        self.respond(
+            "get_address_token_transaction_list",
+            json!({ "address": address, "protocol_type": protocol_type, "page": page, "limit": limit }),
+        )
+    }
+
+    async fn get_brc20_balance_oklink(
+        &self,
+        address: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<OkLinkResp<OkLinkBalancePage>> {
+        self.respond(
+            "get_brc20_balance_oklink",
+            json!({ "address": address, "page": page, "page_size": page_size }),
+        )
+    }
+
+    async fn get_brc20_token_detail_oklink(
+        &self,
+        address: &str,
+        tick: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<OkLinkResp<OkLinkBalanceDetail>> {
+        self.respond(
+            "get_brc20_token_detail_oklink",
+            json!({ "address": address, "tick": tick, "page": page, "page_size": page_size }),
+        )
+    }
+
+    async fn address_inscription_list_oklink(
+        &self,
+        address: &str,
+        page: usize,
+        page_size: usize,
+    ) -> Result<Value> {
+        self.respond(
+            "address_inscription_list_oklink",
+            json!({ "address": address, "page": page, "page_size": page_size }),
+        )
+    }
+
+    async fn publish_tx(&self, signed_tx: &str) -> Result<PublishTxInfo> {
+        self.respond("publish_tx", json!({ "signed_tx": signed_tx }))
+    }
+
+    async fn get_btc_utxo_oklink(
+        &self,
+        address: &str,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<UtxoList>> {
+        self.respond(
+            "get_btc_utxo_oklink",
+            json!({ "address": address, "page": page, "limit": limit }),
+        )
+    }
+
+    async fn get_utxo_by_inscription_id_oklink(
+        &self,
+        inscription_id: &str,
+        inscription_number: &str,
+    ) -> Result<OkLinkResp<InscriptionOk>> {
+        self.respond(
+            "get_utxo_by_inscription_id_oklink",
+            json!({ "inscription_id": inscription_id, "inscription_number": inscription_number }),
+        )
+    }
+
+    async fn get_btc_transaction_list_oklink(
+        &self,
+        height: usize,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<BlockTransactionList>> {
+        self.respond(
+            "get_btc_transaction_list_oklink",
+            json!({ "height": height, "page": page, "limit": limit }),
+        )
+    }
+
+    async fn get_btc_transaction_list_multi_oklink(
+        &self,
+        from_block: usize,
+        to_block: usize,
+        page: usize,
+        limit: usize,
+    ) -> Result<OkLinkResp<BlockTransactionListMulti>> {
+        self.respond(
+            "get_btc_transaction_list_multi_oklink",
+            json!({ "from_block": from_block, "to_block": to_block, "page": page, "limit": limit }),
+        )
+    }
+
+    async fn get_block_fills(&self, height: usize) -> Result<OkLinkResp<BlockFills>> {
+        self.respond("get_block_fills", json!({ "height": height }))
+    }
+
+    async fn get_block_list(&self, page: usize, limit: usize) -> Result<OkLinkResp<BlockList>> {
+        self.respond("get_block_list", json!({ "page": page, "limit": limit }))
+    }
+
+    async fn get_block_height_by_time(
+        &self,
+        time: u64,
+        closest: Closest,
+    ) -> Result<OkLinkResp<BlockHeightByTime>> {
+        self.respond(
+            "get_block_height_by_time",
+            json!({ "time": time, "closest": closest }),
+        )
+    }
+
+    async fn get_chain_info(&self) -> Result<OkLinkResp<BlockChainInfo>> {
+        self.respond("get_chain_info", json!({}))
+    }
+
+    async fn get_blockchain_summary(&self) -> Result<OkLinkResp<BlockchainSummary>> {
+        self.respond("get_blockchain_summary", json!({}))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 依赖 `OkLinkApi` 的业务函数示例。
+    async fn last_height<A: OkLinkApi>(api: &A) -> Result<u64> {
+        let info = api.get_chain_info().await?;
+        Ok(info.data.first().map(|i| i.last_height).unwrap_or_default())
+    }
+
+    #[tokio::test]
+    async fn test_canned_responses_and_calls() {
+        let mock = MockOkLinkApi::new();
+        let info = json!({
+            "code": "0",
+            "msg": "",
+            "data": [{
+                "chainFullName": "Bitcoin",
+                "chainShortName": "BTC",
+                "symbol": "BTC",
+                "rank": "1",
+                "mineable": true,
+                "algorithm": "SHA-256",
+                "consensus": "PoW",
+                "diffEstimation": "65.70T",
+                "currentDiff": "61.03T",
+                "diffAdjustTime": "1698582329000",
+                "circulatingSupply": "19517050",
+                "totalSupply": "21000000",
+                "tps": "3.16",
+                "lastHeight": "812742",
+                "lastBlockTime": "1697623175000",
+                "issueDate": "1231006505000"
+            }]
+        });
+        mock.push_ok("get_chain_info", &info).push_err(
+            "get_chain_info",
+            OkLinkError::Api {
+                code: "50038".to_string(),
+                msg: "chain not supported".to_string(),
+            },
+        );
+
+        assert_eq!(last_height(&mock).await.unwrap(), 812742);
+        assert!(matches!(
+            last_height(&mock).await,
+            Err(OkLinkError::Api { .. })
+        ));
+        assert!(matches!(
+            last_height(&mock).await,
+            Err(OkLinkError::InvalidRequest(_))
+        ));
+        assert_eq!(mock.calls_to("get_chain_info").len(), 3);
+
+        mock.get_btc_utxo_oklink("bc1q", 2, 50).await.unwrap_err();
+        assert_eq!(
+            mock.calls().last().unwrap().args,
+            json!({ "address": "bc1q", "page": 2, "limit": 50 })
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// 列表接口通用的分页信息。
+///
+/// OkLink 以字符串返回分页字段（如 `"totalPage": "635"`），这里统一解析为数字，
+/// 并通过 `#[serde(flatten)]` 嵌入到每个列表响应中。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PageInfo {
+    /// 当前页码
+    #[serde(deserialize_with = "de_u32")]
+    pub page: u32,
+    /// 每页限制数量
+    #[serde(deserialize_with = "de_u32")]
+    pub limit: u32,
+    /// 总页数
+    #[serde(deserialize_with = "de_u32")]
+    pub total_page: u32,
+}
+
+impl PageInfo {
+    /// 是否还有下一页。
+    pub fn has_next_page(&self) -> bool {
+        self.page < self.total_page
+    }
+}
+
+/// `Paginated` 为所有列表接口的响应提供统一的访问方式，
+/// 便于导出、`Stream`、进度条等通用代码处理不同的列表接口。
+pub trait Paginated {
+    type Item;
+
+    /// 分页信息。
+    fn page_info(&self) -> &PageInfo;
+
+    /// 当前页的数据列表。
+    fn items(&self) -> &[Self::Item];
+
+    /// 取出当前页的数据列表。
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// 分页字段缺失或为空字符串时视为 0。
+fn de_u32<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    crate::lenient::option::deserialize(deserializer).map(Option::unwrap_or_default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::UtxoList;
+
+    #[test]
+    fn test_flattened_page_info() {
+        let list: UtxoList =
+            serde_json::from_str(r#"{"page":"2","limit":"50","totalPage":"3","utxoList":[]}"#)
+                .unwrap();
+        assert_eq!(
+            list.page_info,
+            PageInfo {
+                page: 2,
+                limit: 50,
+                total_page: 3
+            }
+        );
+        assert!(list.page_info().has_next_page());
+        assert!(list.items().is_empty());
+    }
+}
@@ -0,0 +1,2051 @@
+//! 各接口的请求参数。
+//!
+//! 每个接口对应一个可序列化的参数结构体，包含 OkLink 文档中该接口支持的全部过滤条件。
+//! 参数通过 `with_*` 方法设置，发送前会先校验（如 `limit` 不超过 [`MAX_LIMIT`]、
+//! 起始高度不大于结束高度），校验失败返回 `OkLinkError::InvalidRequest`。
+//!
+//! 参数通过 `serde_urlencoded` 编码为查询字符串，参数值会被正确地百分号编码
+//! （如包含空格、`&` 或 Unicode 的 BRC-20 `tick`），值为 `None` 的可选参数不会出现在 URL 中。
+//! 按链查询的接口未指定链时，使用客户端当前的链。
+//!
+//! ```ignore
+//! let params = AddressUtxoParams::new("bc1q...").with_page(2).with_limit(100);
+//! let utxo = client.query(&params).await?;
+//! ```
+
+use crate::{
+    error::{OkLinkError, Result},
+    resp::{AddressSummary, BalanceMulti, OkLinkBalanceDetail, OkLinkBalancePage, OkLinkResp},
+    types::{
+        AddressInscriptionList, AddressTransactionList, BlockChainInfo, BlockFills,
+        BlockHeightByTime, BlockList, BlockTransactionList, BlockTransactionListMulti,
+        BlockchainSummary, InscriptionOk, InternalTransactionList, NormalTransactionList, OkApiUri,
+        TokenTransactionList, UtxoList,
+    },
+};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+/// 分页接口每页的最大条数。
+pub const MAX_LIMIT: usize = 100;
+
+/// 分页接口默认的每页条数。
+pub const DEFAULT_LIMIT: usize = 20;
+
+/// `/address/balance-multi` 每次请求最多的地址数。
+pub const MAX_BALANCE_ADDRESSES: usize = 100;
+
+/// `/address/*-transaction-list-multi` 每次请求最多的地址数。
+pub const MAX_TRANSACTION_ADDRESSES: usize = 20;
+
+/// 一个 GET 接口的请求参数。
+pub trait EndpointParams: Serialize {
+    /// 参数对应的接口。
+    const URI: OkApiUri;
+
+    /// 接口是否按链查询（需要 `chainShortName`）。
+    const CHAIN_SCOPED: bool = false;
+
+    /// 接口返回的数据类型。
+    type Output: DeserializeOwned;
+
+    /// 请求指定的链，`None` 表示使用客户端当前的链。
+    fn chain(&self) -> Option<&str> {
+        None
+    }
+
+    /// 校验参数。
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// 校验并编码为相对于 API 基础地址的路径与查询字符串，如 `/blockchain/info?chainShortName=btc`。
+    ///
+    /// 未指定链时不包含 `chainShortName`，由客户端在发送时补充。
+    fn to_uri(&self) -> Result<String> {
+        encode(self, None)
+    }
+}
+
+/// 编码参数；按链查询的接口未指定链时，把 `default_chain` 作为第一个参数。
+pub(crate) fn encode<P: EndpointParams + ?Sized>(
+    params: &P,
+    default_chain: Option<&str>,
+) -> Result<String> {
+    params.validate()?;
+    let invalid = |e: serde_urlencoded::ser::Error| {
+        OkLinkError::InvalidRequest(format!("invalid query parameters: {}", e))
+    };
+
+    let mut query = serde_urlencoded::to_string(params).map_err(invalid)?;
+    if let Some(chain) = default_chain.filter(|_| P::CHAIN_SCOPED && params.chain().is_none()) {
+        let chain = serde_urlencoded::to_string([("chainShortName", chain)]).map_err(invalid)?;
+        query = if query.is_empty() {
+            chain
+        } else {
+            format!("{}&{}", chain, query)
+        };
+    }
+
+    if query.is_empty() {
+        Ok(P::URI.as_str().to_string())
+    } else {
+        Ok(format!("{}?{}", P::URI.as_str(), query))
+    }
+}
+
+fn check_not_empty(name: &str, value: &str) -> Result<()> {
+    if value.trim().is_empty() {
+        return Err(OkLinkError::InvalidRequest(format!(
+            "{} cannot be empty",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// 校验以英文逗号分隔的地址列表。
+fn check_addresses(addresses: &str, max: usize) -> Result<()> {
+    check_not_empty("address", addresses)?;
+    let count = addresses.split(',').count();
+    if count > max {
+        return Err(OkLinkError::InvalidRequest(format!(
+            "at most {} addresses per request, got {}",
+            max, count
+        )));
+    }
+    Ok(())
+}
+
+fn check_page(page: usize, limit: usize) -> Result<()> {
+    if page == 0 {
+        return Err(OkLinkError::InvalidRequest(
+            "page starts from 1".to_string(),
+        ));
+    }
+    if limit == 0 || limit > MAX_LIMIT {
+        return Err(OkLinkError::InvalidRequest(format!(
+            "limit must be between 1 and {}, got {}",
+            MAX_LIMIT, limit
+        )));
+    }
+    Ok(())
+}
+
+fn check_range(name: &str, start: Option<u64>, end: Option<u64>) -> Result<()> {
+    if let (Some(start), Some(end)) = (start, end)
+        && start > end
+    {
+        return Err(OkLinkError::InvalidRequest(format!(
+            "{} range start {} is greater than end {}",
+            name, start, end
+        )));
+    }
+    Ok(())
+}
+
+/// 交易列表的协议类型（`protocolType`）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ProtocolType {
+    /// 普通交易
+    #[serde(rename = "transaction")]
+    Transaction,
+    /// 内部交易
+    #[serde(rename = "internal")]
+    Internal,
+    /// ERC-20 等同质化代币转账
+    #[serde(rename = "token_20")]
+    Token20,
+    /// ERC-721 代币转账
+    #[serde(rename = "token_721")]
+    Token721,
+    /// ERC-1155 代币转账
+    #[serde(rename = "token_1155")]
+    Token1155,
+    /// TRC-10 代币转账
+    #[serde(rename = "token_10")]
+    Token10,
+}
+
+/// 地址交易的方向（`isFromOrTo`）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// 地址为发送方
+    From,
+    /// 地址为接收方
+    To,
+}
+
+/// 按时间查询区块高度时，取该时间之前还是之后最近的区块（`closest`）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Closest {
+    /// 不晚于该时间的最后一个区块
+    #[default]
+    Before,
+    /// 不早于该时间的第一个区块
+    After,
+}
+
+/// `/tokenprice/market-data` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenPriceMarketDataParams {
+    chain_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_contract_address: Option<String>,
+}
+
+impl TokenPriceMarketDataParams {
+    /// 查询链 ID 为 `chain_id` 的链上代币，不指定合约地址时返回原生代币。
+    pub fn new(chain_id: u64) -> Self {
+        Self {
+            chain_id,
+            token_contract_address: None,
+        }
+    }
+
+    /// 代币合约地址。
+    pub fn with_token_contract_address(mut self, address: impl Into<String>) -> Self {
+        self.token_contract_address = Some(address.into());
+        self
+    }
+}
+
+impl EndpointParams for TokenPriceMarketDataParams {
+    const URI: OkApiUri = OkApiUri::TokenPriceMarketData;
+    type Output = Value;
+}
+
+/// `/address/address-summary` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressSummaryParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    address: String,
+}
+
+impl AddressSummaryParams {
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            chain_short_name: None,
+            address: address.into(),
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+}
+
+impl EndpointParams for AddressSummaryParams {
+    const URI: OkApiUri = OkApiUri::AddressSummary;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<AddressSummary>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_not_empty("address", &self.address)
+    }
+}
+
+/// `/address/balance-multi` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceMultiParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    address: String,
+}
+
+impl BalanceMultiParams {
+    /// `address` 可以是单个地址，也可以是以英文逗号分隔的多个地址（最多
+    /// [`MAX_BALANCE_ADDRESSES`] 个）。
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            chain_short_name: None,
+            address: address.into(),
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+}
+
+impl EndpointParams for BalanceMultiParams {
+    const URI: OkApiUri = OkApiUri::BalanceMulti;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<BalanceMulti>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_addresses(&self.address, MAX_BALANCE_ADDRESSES)
+    }
+}
+
+/// `/btc/address-balance-list` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Brc20BalanceListParams {
+    address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token: Option<String>,
+    limit: usize,
+    page: usize,
+}
+
+impl Brc20BalanceListParams {
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            token: None,
+            limit: DEFAULT_LIMIT,
+            page: 1,
+        }
+    }
+
+    /// 只查询指定的代币。
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for Brc20BalanceListParams {
+    const URI: OkApiUri = OkApiUri::BtcAddressBalanceList;
+    type Output = OkLinkResp<OkLinkBalancePage>;
+
+    fn validate(&self) -> Result<()> {
+        check_not_empty("address", &self.address)?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/btc/address-balance-details` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Brc20BalanceDetailParams {
+    address: String,
+    token: String,
+    page: usize,
+    limit: usize,
+}
+
+impl Brc20BalanceDetailParams {
+    /// `token` 为代币符号（tick）。
+    pub fn new(address: impl Into<String>, token: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            token: token.into(),
+            page: 1,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for Brc20BalanceDetailParams {
+    const URI: OkApiUri = OkApiUri::BtcAddressBalanceDetail;
+    type Output = OkLinkResp<OkLinkBalanceDetail>;
+
+    fn validate(&self) -> Result<()> {
+        check_not_empty("address", &self.address)?;
+        check_not_empty("token", &self.token)?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/inscription/address-inscription-list` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressInscriptionListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    protocol_type: String,
+    address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_inscription_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    page: usize,
+    limit: usize,
+}
+
+impl AddressInscriptionListParams {
+    /// 默认查询 `brc20` 协议的铭文。
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            chain_short_name: None,
+            protocol_type: "brc20".to_string(),
+            address: address.into(),
+            token_inscription_id: None,
+            symbol: None,
+            page: 1,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 铭文协议类型，如 `brc20`、`runes`、`src20`、`arc20`、`ordinals_nft`。
+    pub fn with_protocol_type(mut self, protocol_type: impl Into<String>) -> Self {
+        self.protocol_type = protocol_type.into();
+        self
+    }
+
+    /// 只查询指定代币（部署铭文 ID）的铭文。
+    pub fn with_token_inscription_id(mut self, id: impl Into<String>) -> Self {
+        self.token_inscription_id = Some(id.into());
+        self
+    }
+
+    /// 只查询指定代币符号的铭文。
+    pub fn with_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for AddressInscriptionListParams {
+    const URI: OkApiUri = OkApiUri::InscriptionAddressInscriptionList;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<AddressInscriptionList>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_not_empty("address", &self.address)?;
+        check_not_empty("protocolType", &self.protocol_type)?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/transaction/publish-tx` 的请求体。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishTxParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    signed_tx: String,
+}
+
+impl PublishTxParams {
+    pub fn new(signed_tx: impl Into<String>) -> Self {
+        Self {
+            chain_short_name: None,
+            signed_tx: signed_tx.into(),
+        }
+    }
+
+    /// 广播到的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        check_not_empty("signedTx", &self.signed_tx)
+    }
+
+    /// 补充默认的链。
+    pub(crate) fn or_chain(mut self, chain: &str) -> Self {
+        self.chain_short_name
+            .get_or_insert_with(|| chain.to_string());
+        self
+    }
+}
+
+/// `/address/utxo` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressUtxoParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    address: String,
+    page: usize,
+    limit: usize,
+}
+
+impl AddressUtxoParams {
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            chain_short_name: None,
+            address: address.into(),
+            page: 1,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for AddressUtxoParams {
+    const URI: OkApiUri = OkApiUri::AddressUtxo;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<UtxoList>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_not_empty("address", &self.address)?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// BRC-20 铭文操作类型（`actionType`）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InscriptionAction {
+    Deploy,
+    Mint,
+    InscribeTransfer,
+    Transfer,
+}
+
+/// `/btc/transaction-list` 的参数，所有过滤条件都是可选的。
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InscriptionTransactionListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inscription_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inscription_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    action_type: Option<InscriptionAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tx_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<usize>,
+}
+
+impl InscriptionTransactionListParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 与该地址相关的交易。
+    pub fn with_address(mut self, address: impl Into<String>) -> Self {
+        self.address = Some(address.into());
+        self
+    }
+
+    /// 代币符号（tick）。
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    pub fn with_inscription_id(mut self, inscription_id: impl Into<String>) -> Self {
+        self.inscription_id = Some(inscription_id.into());
+        self
+    }
+
+    pub fn with_inscription_number(mut self, inscription_number: impl Into<String>) -> Self {
+        self.inscription_number = Some(inscription_number.into());
+        self
+    }
+
+    pub fn with_action_type(mut self, action_type: InscriptionAction) -> Self {
+        self.action_type = Some(action_type);
+        self
+    }
+
+    pub fn with_from_address(mut self, address: impl Into<String>) -> Self {
+        self.from_address = Some(address.into());
+        self
+    }
+
+    pub fn with_to_address(mut self, address: impl Into<String>) -> Self {
+        self.to_address = Some(address.into());
+        self
+    }
+
+    pub fn with_tx_id(mut self, tx_id: impl Into<String>) -> Self {
+        self.tx_id = Some(tx_id.into());
+        self
+    }
+
+    pub fn with_block_height(mut self, height: u64) -> Self {
+        self.block_height = Some(height);
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl EndpointParams for InscriptionTransactionListParams {
+    const URI: OkApiUri = OkApiUri::BrcTransactionList;
+    type Output = OkLinkResp<InscriptionOk>;
+
+    fn validate(&self) -> Result<()> {
+        check_page(self.page.unwrap_or(1), self.limit.unwrap_or(DEFAULT_LIMIT))
+    }
+}
+
+/// `/block/transaction-list` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockTransactionListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    height: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    protocol_type: Option<ProtocolType>,
+    limit: usize,
+    page: usize,
+}
+
+impl BlockTransactionListParams {
+    pub fn new(height: u64) -> Self {
+        Self {
+            chain_short_name: None,
+            height,
+            protocol_type: None,
+            limit: DEFAULT_LIMIT,
+            page: 1,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 只查询指定类型的交易，默认为普通交易。
+    pub fn with_protocol_type(mut self, protocol_type: ProtocolType) -> Self {
+        self.protocol_type = Some(protocol_type);
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for BlockTransactionListParams {
+    const URI: OkApiUri = OkApiUri::BlockTransaction;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<BlockTransactionList>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/block/transaction-list-multi` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockTransactionListMultiParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    start_block_height: u64,
+    end_block_height: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    protocol_type: Option<ProtocolType>,
+    limit: usize,
+    page: usize,
+}
+
+impl BlockTransactionListMultiParams {
+    /// 查询 `[start_block_height, end_block_height]` 区间内的交易。
+    pub fn new(start_block_height: u64, end_block_height: u64) -> Self {
+        Self {
+            chain_short_name: None,
+            start_block_height,
+            end_block_height,
+            protocol_type: None,
+            limit: DEFAULT_LIMIT,
+            page: 1,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 只查询指定类型的交易，默认为普通交易。
+    pub fn with_protocol_type(mut self, protocol_type: ProtocolType) -> Self {
+        self.protocol_type = Some(protocol_type);
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for BlockTransactionListMultiParams {
+    const URI: OkApiUri = OkApiUri::BlockTransactionMulti;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<BlockTransactionListMulti>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_range(
+            "block height",
+            Some(self.start_block_height),
+            Some(self.end_block_height),
+        )?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/address/transaction-list` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressTransactionListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    protocol_type: Option<ProtocolType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_contract_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_block_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_block_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_from_or_to: Option<Direction>,
+    page: usize,
+    limit: usize,
+}
+
+impl AddressTransactionListParams {
+    /// 查询地址的全部交易，默认包含所有类型。
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            chain_short_name: None,
+            address: address.into(),
+            protocol_type: None,
+            token_contract_address: None,
+            start_block_height: None,
+            end_block_height: None,
+            is_from_or_to: None,
+            page: 1,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 只查询指定类型的交易。
+    pub fn with_protocol_type(mut self, protocol_type: ProtocolType) -> Self {
+        self.protocol_type = Some(protocol_type);
+        self
+    }
+
+    /// 只查询指定代币合约的转账。
+    pub fn with_token_contract_address(mut self, address: impl Into<String>) -> Self {
+        self.token_contract_address = Some(address.into());
+        self
+    }
+
+    /// 起始区块高度（含）。
+    pub fn with_start_block_height(mut self, height: u64) -> Self {
+        self.start_block_height = Some(height);
+        self
+    }
+
+    /// 结束区块高度（含）。
+    pub fn with_end_block_height(mut self, height: u64) -> Self {
+        self.end_block_height = Some(height);
+        self
+    }
+
+    /// 只查询地址作为发送方或接收方的交易。
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.is_from_or_to = Some(direction);
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for AddressTransactionListParams {
+    const URI: OkApiUri = OkApiUri::AddressTransaction;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<AddressTransactionList>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_not_empty("address", &self.address)?;
+        check_range(
+            "block height",
+            self.start_block_height,
+            self.end_block_height,
+        )?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/address/normal-transaction-list` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressNormalTransactionListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_block_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_block_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_from_or_to: Option<Direction>,
+    page: usize,
+    limit: usize,
+}
+
+impl AddressNormalTransactionListParams {
+    /// 查询地址的普通交易。
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            chain_short_name: None,
+            address: address.into(),
+            start_block_height: None,
+            end_block_height: None,
+            is_from_or_to: None,
+            page: 1,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 起始区块高度（含）。
+    pub fn with_start_block_height(mut self, height: u64) -> Self {
+        self.start_block_height = Some(height);
+        self
+    }
+
+    /// 结束区块高度（含）。
+    pub fn with_end_block_height(mut self, height: u64) -> Self {
+        self.end_block_height = Some(height);
+        self
+    }
+
+    /// 只查询地址作为发送方或接收方的交易。
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.is_from_or_to = Some(direction);
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for AddressNormalTransactionListParams {
+    const URI: OkApiUri = OkApiUri::AddressNormalTransaction;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<NormalTransactionList>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_not_empty("address", &self.address)?;
+        check_range(
+            "block height",
+            self.start_block_height,
+            self.end_block_height,
+        )?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/address/internal-transaction-list` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressInternalTransactionListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_block_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_block_height: Option<u64>,
+    page: usize,
+    limit: usize,
+}
+
+impl AddressInternalTransactionListParams {
+    /// 查询地址的内部交易。
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            chain_short_name: None,
+            address: address.into(),
+            start_block_height: None,
+            end_block_height: None,
+            page: 1,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 起始区块高度（含）。
+    pub fn with_start_block_height(mut self, height: u64) -> Self {
+        self.start_block_height = Some(height);
+        self
+    }
+
+    /// 结束区块高度（含）。
+    pub fn with_end_block_height(mut self, height: u64) -> Self {
+        self.end_block_height = Some(height);
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for AddressInternalTransactionListParams {
+    const URI: OkApiUri = OkApiUri::AddressInternalTransaction;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<InternalTransactionList>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_not_empty("address", &self.address)?;
+        check_range(
+            "block height",
+            self.start_block_height,
+            self.end_block_height,
+        )?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/address/token-transaction-list` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressTokenTransactionListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    address: String,
+    protocol_type: ProtocolType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_contract_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_block_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_block_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_from_or_to: Option<Direction>,
+    page: usize,
+    limit: usize,
+}
+
+impl AddressTokenTransactionListParams {
+    /// 查询地址的 `protocol_type` 类型代币转账，如 [`ProtocolType::Token20`]。
+    pub fn new(address: impl Into<String>, protocol_type: ProtocolType) -> Self {
+        Self {
+            chain_short_name: None,
+            address: address.into(),
+            protocol_type,
+            token_contract_address: None,
+            start_block_height: None,
+            end_block_height: None,
+            is_from_or_to: None,
+            page: 1,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 只查询指定代币合约的转账。
+    pub fn with_token_contract_address(mut self, address: impl Into<String>) -> Self {
+        self.token_contract_address = Some(address.into());
+        self
+    }
+
+    /// 起始区块高度（含）。
+    pub fn with_start_block_height(mut self, height: u64) -> Self {
+        self.start_block_height = Some(height);
+        self
+    }
+
+    /// 结束区块高度（含）。
+    pub fn with_end_block_height(mut self, height: u64) -> Self {
+        self.end_block_height = Some(height);
+        self
+    }
+
+    /// 只查询地址作为发送方或接收方的交易。
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.is_from_or_to = Some(direction);
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for AddressTokenTransactionListParams {
+    const URI: OkApiUri = OkApiUri::AddressTokenTransaction;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<TokenTransactionList>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_not_empty("address", &self.address)?;
+        check_range(
+            "block height",
+            self.start_block_height,
+            self.end_block_height,
+        )?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/address/transaction-list-multi` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressTransactionListMultiParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    address: String,
+    start_block_height: u64,
+    end_block_height: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_from_or_to: Option<Direction>,
+    page: usize,
+    limit: usize,
+}
+
+impl AddressTransactionListMultiParams {
+    /// 查询 `addresses`（最多 [`MAX_TRANSACTION_ADDRESSES`] 个）在 `[start_block_height, end_block_height]` 区间内的交易。
+    pub fn new(addresses: &[&str], start_block_height: u64, end_block_height: u64) -> Self {
+        Self {
+            chain_short_name: None,
+            address: addresses.join(","),
+            start_block_height,
+            end_block_height,
+            is_from_or_to: None,
+            page: 1,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 只查询地址作为发送方或接收方的交易。
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.is_from_or_to = Some(direction);
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for AddressTransactionListMultiParams {
+    const URI: OkApiUri = OkApiUri::AddressTransactionMulti;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<NormalTransactionList>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_addresses(&self.address, MAX_TRANSACTION_ADDRESSES)?;
+        check_range(
+            "block height",
+            Some(self.start_block_height),
+            Some(self.end_block_height),
+        )?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/address/normal-transaction-list-multi` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressNormalTransactionListMultiParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    address: String,
+    start_block_height: u64,
+    end_block_height: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_from_or_to: Option<Direction>,
+    page: usize,
+    limit: usize,
+}
+
+impl AddressNormalTransactionListMultiParams {
+    /// 查询 `addresses`（最多 [`MAX_TRANSACTION_ADDRESSES`] 个）在 `[start_block_height, end_block_height]` 区间内的普通交易。
+    pub fn new(addresses: &[&str], start_block_height: u64, end_block_height: u64) -> Self {
+        Self {
+            chain_short_name: None,
+            address: addresses.join(","),
+            start_block_height,
+            end_block_height,
+            is_from_or_to: None,
+            page: 1,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 只查询地址作为发送方或接收方的交易。
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.is_from_or_to = Some(direction);
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for AddressNormalTransactionListMultiParams {
+    const URI: OkApiUri = OkApiUri::AddressNormalTransactionMulti;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<NormalTransactionList>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_addresses(&self.address, MAX_TRANSACTION_ADDRESSES)?;
+        check_range(
+            "block height",
+            Some(self.start_block_height),
+            Some(self.end_block_height),
+        )?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/address/internal-transaction-list-multi` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressInternalTransactionListMultiParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    address: String,
+    start_block_height: u64,
+    end_block_height: u64,
+    page: usize,
+    limit: usize,
+}
+
+impl AddressInternalTransactionListMultiParams {
+    /// 查询 `addresses`（最多 [`MAX_TRANSACTION_ADDRESSES`] 个）在 `[start_block_height, end_block_height]` 区间内的内部交易。
+    pub fn new(addresses: &[&str], start_block_height: u64, end_block_height: u64) -> Self {
+        Self {
+            chain_short_name: None,
+            address: addresses.join(","),
+            start_block_height,
+            end_block_height,
+            page: 1,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for AddressInternalTransactionListMultiParams {
+    const URI: OkApiUri = OkApiUri::AddressInternalTransactionMulti;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<InternalTransactionList>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_addresses(&self.address, MAX_TRANSACTION_ADDRESSES)?;
+        check_range(
+            "block height",
+            Some(self.start_block_height),
+            Some(self.end_block_height),
+        )?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/address/token-transaction-list-multi` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressTokenTransactionListMultiParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    address: String,
+    start_block_height: u64,
+    end_block_height: u64,
+    protocol_type: ProtocolType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_contract_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_from_or_to: Option<Direction>,
+    page: usize,
+    limit: usize,
+}
+
+impl AddressTokenTransactionListMultiParams {
+    /// 查询 `addresses`（最多 [`MAX_TRANSACTION_ADDRESSES`] 个）在 `[start_block_height, end_block_height]` 区间内的代币转账。
+    pub fn new(
+        addresses: &[&str],
+        protocol_type: ProtocolType,
+        start_block_height: u64,
+        end_block_height: u64,
+    ) -> Self {
+        Self {
+            chain_short_name: None,
+            address: addresses.join(","),
+            start_block_height,
+            end_block_height,
+            protocol_type,
+            token_contract_address: None,
+            is_from_or_to: None,
+            page: 1,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 只查询指定代币合约的转账。
+    pub fn with_token_contract_address(mut self, address: impl Into<String>) -> Self {
+        self.token_contract_address = Some(address.into());
+        self
+    }
+
+    /// 只查询地址作为发送方或接收方的交易。
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.is_from_or_to = Some(direction);
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for AddressTokenTransactionListMultiParams {
+    const URI: OkApiUri = OkApiUri::AddressTokenTransactionMulti;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<TokenTransactionList>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_addresses(&self.address, MAX_TRANSACTION_ADDRESSES)?;
+        check_range(
+            "block height",
+            Some(self.start_block_height),
+            Some(self.end_block_height),
+        )?;
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/block/block-fills` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockFillsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    height: u64,
+}
+
+impl BlockFillsParams {
+    pub fn new(height: u64) -> Self {
+        Self {
+            chain_short_name: None,
+            height,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+}
+
+impl EndpointParams for BlockFillsParams {
+    const URI: OkApiUri = OkApiUri::BlockFills;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<BlockFills>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+}
+
+/// `/block/block-list` 的参数，区块按高度从新到旧返回。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<u64>,
+    limit: usize,
+    page: usize,
+}
+
+impl Default for BlockListParams {
+    fn default() -> Self {
+        Self {
+            chain_short_name: None,
+            height: None,
+            limit: DEFAULT_LIMIT,
+            page: 1,
+        }
+    }
+}
+
+impl BlockListParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 只查询指定高度的区块。
+    pub fn with_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// 页码，从 1 开始。
+    pub fn with_page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// 每页的大小，最大为 [`MAX_LIMIT`]。
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl EndpointParams for BlockListParams {
+    const URI: OkApiUri = OkApiUri::BlockList;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<BlockList>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_page(self.page, self.limit)
+    }
+}
+
+/// `/block/block-height-by-time` 的参数。
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHeightByTimeParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+    time: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    closest: Option<Closest>,
+}
+
+impl BlockHeightByTimeParams {
+    /// 查询 `time`（Unix 毫秒时间戳）附近的区块，默认为 [`Closest::Before`]。
+    pub fn new(time: u64) -> Self {
+        Self {
+            chain_short_name: None,
+            time,
+            closest: None,
+        }
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+
+    /// 取该时间之前还是之后最近的区块。
+    pub fn with_closest(mut self, closest: Closest) -> Self {
+        self.closest = Some(closest);
+        self
+    }
+}
+
+impl EndpointParams for BlockHeightByTimeParams {
+    const URI: OkApiUri = OkApiUri::BlockHeightByTime;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<BlockHeightByTime>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+}
+
+/// `/blockchain/info` 的参数。
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainInfoParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+}
+
+impl ChainInfoParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 查询的链，默认为客户端当前的链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+}
+
+impl EndpointParams for ChainInfoParams {
+    const URI: OkApiUri = OkApiUri::ChainInfo;
+    const CHAIN_SCOPED: bool = true;
+    type Output = OkLinkResp<BlockChainInfo>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+}
+
+/// `/blockchain/summary` 的参数。
+///
+/// 不指定链时返回 OkLink 支持的全部链，不会使用客户端当前的链。
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockchainSummaryParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_short_name: Option<String>,
+}
+
+impl BlockchainSummaryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 只查询某一条链。
+    pub fn with_chain(mut self, chain: impl Into<String>) -> Self {
+        self.chain_short_name = Some(chain.into());
+        self
+    }
+}
+
+impl EndpointParams for BlockchainSummaryParams {
+    const URI: OkApiUri = OkApiUri::BlockchainSummary;
+    type Output = OkLinkResp<BlockchainSummary>;
+
+    fn chain(&self) -> Option<&str> {
+        self.chain_short_name.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uri<P: EndpointParams>(params: &P) -> String {
+        encode(params, Some("btc")).unwrap()
+    }
+
+    #[test]
+    fn test_token_price_market_data_uri() {
+        let params = TokenPriceMarketDataParams::new(1);
+        assert_eq!(uri(&params), "/tokenprice/market-data?chainId=1");
+
+        let params =
+            params.with_token_contract_address("0xdac17f958d2ee523a2206206994597c13d831ec7");
+        assert_eq!(
+            uri(&params),
+            "/tokenprice/market-data?chainId=1&tokenContractAddress=0xdac17f958d2ee523a2206206994597c13d831ec7"
+        );
+    }
+
+    #[test]
+    fn test_address_summary_uri() {
+        let params = AddressSummaryParams::new("bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh");
+        assert_eq!(
+            uri(&params),
+            "/address/address-summary?chainShortName=btc&address=bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh"
+        );
+        assert!(AddressSummaryParams::new(" ").to_uri().is_err());
+    }
+
+    #[test]
+    fn test_balance_multi_uri() {
+        let params = BalanceMultiParams::new("0xa,0xb").with_chain("eth");
+        assert_eq!(
+            uri(&params),
+            "/address/balance-multi?chainShortName=eth&address=0xa%2C0xb"
+        );
+    }
+
+    #[test]
+    fn test_brc20_balance_list_uri() {
+        let params = Brc20BalanceListParams::new("bc1q")
+            .with_page(2)
+            .with_limit(50);
+        assert_eq!(
+            uri(&params),
+            "/btc/address-balance-list?address=bc1q&limit=50&page=2"
+        );
+
+        let params = params.with_token("ordi");
+        assert_eq!(
+            uri(&params),
+            "/btc/address-balance-list?address=bc1q&token=ordi&limit=50&page=2"
+        );
+    }
+
+    #[test]
+    fn test_brc20_balance_detail_uri() {
+        let params = Brc20BalanceDetailParams::new("bc1q", "a b&c=d/日");
+        assert_eq!(
+            uri(&params),
+            "/btc/address-balance-details?address=bc1q&token=a+b%26c%3Dd%2F%E6%97%A5&page=1&limit=20"
+        );
+    }
+
+    #[test]
+    fn test_address_inscription_list_uri() {
+        let params = AddressInscriptionListParams::new("bc1q").with_limit(100);
+        assert_eq!(
+            uri(&params),
+            "/inscription/address-inscription-list?chainShortName=btc&protocolType=brc20&address=bc1q&page=1&limit=100"
+        );
+
+        let params = params.with_protocol_type("runes").with_symbol("DOG");
+        assert_eq!(
+            uri(&params),
+            "/inscription/address-inscription-list?chainShortName=btc&protocolType=runes&address=bc1q&symbol=DOG&page=1&limit=100"
+        );
+    }
+
+    #[test]
+    fn test_publish_tx_body() {
+        let params = PublishTxParams::new("0200").or_chain("btc");
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({ "chainShortName": "btc", "signedTx": "0200" })
+        );
+        assert!(PublishTxParams::new("").validate().is_err());
+    }
+
+    #[test]
+    fn test_address_utxo_uri() {
+        let params = AddressUtxoParams::new("bc1q").with_page(3).with_limit(50);
+        assert_eq!(
+            uri(&params),
+            "/address/utxo?chainShortName=btc&address=bc1q&page=3&limit=50"
+        );
+    }
+
+    #[test]
+    fn test_inscription_transaction_list_uri() {
+        let params = InscriptionTransactionListParams::new()
+            .with_inscription_id("abc123i0")
+            .with_inscription_number("42");
+        assert_eq!(
+            uri(&params),
+            "/btc/transaction-list?inscriptionId=abc123i0&inscriptionNumber=42"
+        );
+
+        let params = InscriptionTransactionListParams::new()
+            .with_token("ordi")
+            .with_action_type(InscriptionAction::InscribeTransfer)
+            .with_block_height(800000)
+            .with_limit(100);
+        assert_eq!(
+            uri(&params),
+            "/btc/transaction-list?token=ordi&actionType=inscribeTransfer&blockHeight=800000&limit=100"
+        );
+    }
+
+    #[test]
+    fn test_block_transaction_list_uri() {
+        let params = BlockTransactionListParams::new(800000);
+        assert_eq!(
+            uri(&params),
+            "/block/transaction-list?chainShortName=btc&height=800000&limit=20&page=1"
+        );
+
+        let params = BlockTransactionListParams::new(18809970)
+            .with_chain("eth")
+            .with_protocol_type(ProtocolType::Token20);
+        assert_eq!(
+            uri(&params),
+            "/block/transaction-list?chainShortName=eth&height=18809970&protocolType=token_20&limit=20&page=1"
+        );
+    }
+
+    #[test]
+    fn test_block_transaction_list_multi_uri() {
+        let params = BlockTransactionListMultiParams::new(18809970, 18809972)
+            .with_chain("eth")
+            .with_limit(1);
+        assert_eq!(
+            uri(&params),
+            "/block/transaction-list-multi?chainShortName=eth&startBlockHeight=18809970&endBlockHeight=18809972&limit=1&page=1"
+        );
+    }
+
+    #[test]
+    fn test_address_transaction_list_uri() {
+        let params = AddressTransactionListParams::new("0xabc");
+        assert_eq!(
+            uri(&params),
+            "/address/transaction-list?chainShortName=btc&address=0xabc&page=1&limit=20"
+        );
+
+        let params = params
+            .with_chain("eth")
+            .with_protocol_type(ProtocolType::Token20)
+            .with_token_contract_address("0xdac17f958d2ee523a2206206994597c13d831ec7")
+            .with_start_block_height(18809970)
+            .with_end_block_height(18809972)
+            .with_direction(Direction::To);
+        assert_eq!(
+            uri(&params),
+            "/address/transaction-list?chainShortName=eth&address=0xabc&protocolType=token_20\
+             &tokenContractAddress=0xdac17f958d2ee523a2206206994597c13d831ec7\
+             &startBlockHeight=18809970&endBlockHeight=18809972&isFromOrTo=to&page=1&limit=20"
+        );
+    }
+
+    #[test]
+    fn test_address_normal_transaction_list_uri() {
+        let params = AddressNormalTransactionListParams::new("0xabc")
+            .with_direction(Direction::From)
+            .with_limit(100);
+        assert_eq!(
+            uri(&params),
+            "/address/normal-transaction-list?chainShortName=btc&address=0xabc&isFromOrTo=from&page=1&limit=100"
+        );
+    }
+
+    #[test]
+    fn test_address_internal_transaction_list_uri() {
+        let params = AddressInternalTransactionListParams::new("0xabc").with_start_block_height(1);
+        assert_eq!(
+            uri(&params),
+            "/address/internal-transaction-list?chainShortName=btc&address=0xabc&startBlockHeight=1&page=1&limit=20"
+        );
+    }
+
+    #[test]
+    fn test_address_token_transaction_list_uri() {
+        let params = AddressTokenTransactionListParams::new("0xabc", ProtocolType::Token721)
+            .with_chain("eth")
+            .with_page(2);
+        assert_eq!(
+            uri(&params),
+            "/address/token-transaction-list?chainShortName=eth&address=0xabc&protocolType=token_721&page=2&limit=20"
+        );
+    }
+
+    #[test]
+    fn test_address_transaction_list_multi_uri() {
+        let params = AddressTransactionListMultiParams::new(&["0xa", "0xb"], 18809970, 18809972)
+            .with_direction(Direction::From);
+        assert_eq!(
+            uri(&params),
+            "/address/transaction-list-multi?chainShortName=btc&address=0xa%2C0xb\
+             &startBlockHeight=18809970&endBlockHeight=18809972&isFromOrTo=from&page=1&limit=20"
+        );
+
+        let params = AddressTokenTransactionListMultiParams::new(
+            &["0xa"],
+            ProtocolType::Token20,
+            18809970,
+            18809972,
+        )
+        .with_chain("eth")
+        .with_token_contract_address("0xdac17f958d2ee523a2206206994597c13d831ec7");
+        assert_eq!(
+            uri(&params),
+            "/address/token-transaction-list-multi?chainShortName=eth&address=0xa\
+             &startBlockHeight=18809970&endBlockHeight=18809972&protocolType=token_20\
+             &tokenContractAddress=0xdac17f958d2ee523a2206206994597c13d831ec7&page=1&limit=20"
+        );
+    }
+
+    #[test]
+    fn test_address_limits() {
+        let addresses: Vec<String> = (0..=MAX_TRANSACTION_ADDRESSES)
+            .map(|i| format!("0x{}", i))
+            .collect();
+        let addresses: Vec<&str> = addresses.iter().map(String::as_str).collect();
+        assert!(
+            AddressInternalTransactionListMultiParams::new(&addresses, 1, 2)
+                .to_uri()
+                .is_err()
+        );
+        assert!(
+            AddressInternalTransactionListMultiParams::new(&addresses[1..], 1, 2)
+                .to_uri()
+                .is_ok()
+        );
+        assert!(
+            AddressNormalTransactionListMultiParams::new(&["0xa"], 2, 1)
+                .to_uri()
+                .is_err()
+        );
+        assert!(
+            AddressTransactionListMultiParams::new(&[], 1, 2)
+                .to_uri()
+                .is_err()
+        );
+
+        let addresses = vec!["bc1q"; MAX_BALANCE_ADDRESSES + 1].join(",");
+        assert!(BalanceMultiParams::new(addresses).to_uri().is_err());
+    }
+
+    #[test]
+    fn test_block_fills_uri() {
+        assert_eq!(
+            uri(&BlockFillsParams::new(812742)),
+            "/block/block-fills?chainShortName=btc&height=812742"
+        );
+    }
+
+    #[test]
+    fn test_block_list_uri() {
+        assert_eq!(
+            uri(&BlockListParams::new()),
+            "/block/block-list?chainShortName=btc&limit=20&page=1"
+        );
+        assert_eq!(
+            uri(&BlockListParams::new()
+                .with_chain("eth")
+                .with_height(18809970)
+                .with_limit(100)),
+            "/block/block-list?chainShortName=eth&height=18809970&limit=100&page=1"
+        );
+        assert!(BlockListParams::new().with_limit(101).to_uri().is_err());
+    }
+
+    #[test]
+    fn test_block_height_by_time_uri() {
+        assert_eq!(
+            uri(&BlockHeightByTimeParams::new(1697623175000)),
+            "/block/block-height-by-time?chainShortName=btc&time=1697623175000"
+        );
+        assert_eq!(
+            uri(&BlockHeightByTimeParams::new(1697623175000).with_closest(Closest::After)),
+            "/block/block-height-by-time?chainShortName=btc&time=1697623175000&closest=after"
+        );
+    }
+
+    #[test]
+    fn test_chain_info_uri() {
+        assert_eq!(
+            uri(&ChainInfoParams::new()),
+            "/blockchain/info?chainShortName=btc"
+        );
+        assert_eq!(ChainInfoParams::new().to_uri().unwrap(), "/blockchain/info");
+    }
+
+    #[test]
+    fn test_blockchain_summary_uri() {
+        assert_eq!(uri(&BlockchainSummaryParams::new()), "/blockchain/summary");
+        assert_eq!(
+            uri(&BlockchainSummaryParams::new().with_chain("ETH")),
+            "/blockchain/summary?chainShortName=ETH"
+        );
+    }
+
+    #[test]
+    fn test_address_transaction_validation() {
+        assert!(AddressTransactionListParams::new("").to_uri().is_err());
+        assert!(
+            AddressNormalTransactionListParams::new("0xabc")
+                .with_start_block_height(2)
+                .with_end_block_height(1)
+                .to_uri()
+                .is_err()
+        );
+        assert!(
+            AddressTokenTransactionListParams::new("0xabc", ProtocolType::Token20)
+                .with_limit(0)
+                .to_uri()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validation() {
+        let too_many = AddressUtxoParams::new("bc1q").with_limit(MAX_LIMIT + 1);
+        assert!(matches!(
+            too_many.to_uri(),
+            Err(OkLinkError::InvalidRequest(_))
+        ));
+        assert!(
+            AddressUtxoParams::new("bc1q")
+                .with_page(0)
+                .to_uri()
+                .is_err()
+        );
+        assert!(
+            BlockTransactionListMultiParams::new(10, 9)
+                .to_uri()
+                .is_err()
+        );
+        assert!(
+            InscriptionTransactionListParams::new()
+                .with_limit(0)
+                .to_uri()
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::{sync::Mutex, time::Instant};
+
+pub(crate) const SECONDS_PER_DAY: u64 = 86_400;
+
+/// `RateLimiter` 是一个异步限流器，用于匹配 OkLink API Key 的限额。
+///
+/// 同时支持每秒请求数与每日额度两个预算：每秒请求数使用令牌桶，
+/// 每日额度是在每个 UTC 日零点清零的固定计数，与 OkLink 的额度周期一致，
+/// 任意一个 UTC 日内都不会超过额度。多个任务共享同一个限流器时，
+/// 等待中的调用方按照到达顺序排队（FIFO），不会直接失败。
+///
+/// 内部使用 `tokio::time` 计时，测试中可以通过 `tokio::time::pause` 模拟时钟。
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    budgets: Arc<Mutex<Budgets>>,
+}
+
+#[derive(Debug)]
+struct Budgets {
+    per_second: Option<TokenBucket>,
+    per_day: Option<DailyQuota>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+/// 每个 UTC 日的固定额度。
+#[derive(Debug)]
+struct DailyQuota {
+    limit: u64,
+    used: u64,
+    day: u64,
+    /// 创建时距 Unix 纪元的时长，之后的时间由 `tokio` 时钟推进
+    unix_origin: Duration,
+    origin: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: f64, period_secs: f64) -> Self {
+        TokenBucket {
+            capacity,
+            tokens: capacity,
+            refill_per_sec: capacity / period_secs,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    fn wait_time(&self) -> Duration {
+        if self.tokens >= 1.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((1.0 - self.tokens) / self.refill_per_sec)
+        }
+    }
+}
+
+impl DailyQuota {
+    fn new(limit: u64, unix_origin: Duration) -> Self {
+        DailyQuota {
+            limit,
+            used: 0,
+            day: utc_day(unix_origin),
+            unix_origin,
+            origin: Instant::now(),
+        }
+    }
+
+    fn unix_time(&self, now: Instant) -> Duration {
+        self.unix_origin + now.duration_since(self.origin)
+    }
+
+    fn roll_day(&mut self, now: Instant) {
+        let today = utc_day(self.unix_time(now));
+        if self.day != today {
+            self.day = today;
+            self.used = 0;
+        }
+    }
+
+    /// 额度用完时等到下一个 UTC 日零点。
+    fn wait_time(&self, now: Instant) -> Duration {
+        if self.used < self.limit {
+            Duration::ZERO
+        } else {
+            Duration::from_secs((self.day + 1) * SECONDS_PER_DAY)
+                .saturating_sub(self.unix_time(now))
+        }
+    }
+}
+
+impl RateLimiter {
+    /// 创建一个新的限流器。
+    ///
+    /// # 参数
+    /// - `per_second`: 每秒允许的请求数，`None` 表示不限制。
+    /// - `per_day`: 每个 UTC 日允许的请求数（额度），`None` 表示不限制。
+    pub fn new(per_second: Option<u32>, per_day: Option<u64>) -> Self {
+        Self::with_budgets(
+            per_second
+                .filter(|n| *n > 0)
+                .map(|n| TokenBucket::new(n as f64, 1.0)),
+            per_day
+                .filter(|n| *n > 0)
+                .map(|n| DailyQuota::new(n, unix_now())),
+        )
+    }
+
+    fn with_budgets(per_second: Option<TokenBucket>, per_day: Option<DailyQuota>) -> Self {
+        RateLimiter {
+            budgets: Arc::new(Mutex::new(Budgets {
+                per_second,
+                per_day,
+            })),
+        }
+    }
+
+    /// 只限制每秒请求数的限流器。
+    pub fn per_second(per_second: u32) -> Self {
+        Self::new(Some(per_second), None)
+    }
+
+    /// 等待直到可以发送下一个请求，并消耗一次额度。
+    pub async fn acquire(&self) {
+        // 持有锁期间等待，保证排队的调用方按顺序获得令牌。
+        let mut budgets = self.budgets.lock().await;
+        loop {
+            let now = Instant::now();
+            let mut wait = Duration::ZERO;
+            if let Some(bucket) = &mut budgets.per_second {
+                bucket.refill(now);
+                wait = bucket.wait_time();
+            }
+            if let Some(quota) = &mut budgets.per_day {
+                quota.roll_day(now);
+                wait = wait.max(quota.wait_time(now));
+            }
+            if wait.is_zero() {
+                if let Some(bucket) = &mut budgets.per_second {
+                    bucket.tokens -= 1.0;
+                }
+                if let Some(quota) = &mut budgets.per_day {
+                    quota.used += 1;
+                }
+                return;
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// 返回今日（UTC）剩余的请求额度，未设置每日预算时返回 `None`。
+    pub async fn remaining_today(&self) -> Option<u64> {
+        let mut budgets = self.budgets.lock().await;
+        let quota = budgets.per_day.as_mut()?;
+        quota.roll_day(Instant::now());
+        Some(quota.limit.saturating_sub(quota.used))
+    }
+}
+
+/// 当前时间距 Unix 纪元的时长。
+pub(crate) fn unix_now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// 给定时间（距 Unix 纪元）所在的 UTC 日序号，每日额度在日序号变化时清零。
+pub(crate) fn utc_day(unix: Duration) -> u64 {
+    unix.as_secs() / SECONDS_PER_DAY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_per_second_budget() {
+        let limiter = RateLimiter::per_second(2);
+        let start = Instant::now();
+        for _ in 0..4 {
+            limiter.acquire().await;
+        }
+        // 前两个令牌立即可用，之后每 500ms 补充一个。
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_per_day_budget() {
+        // 从某个 UTC 日的 01:00 开始
+        let day_start = Duration::from_secs(20_000 * SECONDS_PER_DAY);
+        let limiter = RateLimiter::with_budgets(
+            None,
+            Some(DailyQuota::new(2, day_start + Duration::from_secs(3_600))),
+        );
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(limiter.remaining_today().await, Some(0));
+
+        // 额度不会在当天内逐渐恢复
+        tokio::time::advance(Duration::from_secs(12 * 3_600)).await;
+        assert_eq!(limiter.remaining_today().await, Some(0));
+
+        // 等到下一个 UTC 日零点才清零
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert_eq!(start.elapsed().as_secs(), 11 * 3_600);
+        assert_eq!(limiter.remaining_today().await, Some(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_concurrent_callers_queue() {
+        let limiter = RateLimiter::per_second(1);
+        let start = Instant::now();
+        let handles: Vec<_> = (0..3)
+            .map(|_| {
+                let limiter = limiter.clone();
+                tokio::spawn(async move { limiter.acquire().await })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+    }
+}
@@ -1,27 +1,49 @@
+use crate::{
+    lenient::{Amount, Timestamp},
+    page::{PageInfo, Paginated},
+};
+use reqwest::{StatusCode, header::HeaderMap};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 // 定义OKLink余额详情结构体
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OkLinkBalanceDetail {
-    /// 当前页码
-    pub page: String,
-    /// 每页限制数量
-    pub limit: String,
-    /// 总页数
-    pub total_page: String,
+    /// 分页信息
+    #[serde(flatten)]
+    pub page_info: PageInfo,
     /// 代币类型
     pub token_type: String,
     /// 总余额
-    pub balance: String,
+    #[serde(with = "crate::lenient")]
+    pub balance: Amount,
     /// 可用余额
-    pub available_balance: String,
+    #[serde(with = "crate::lenient")]
+    pub available_balance: Amount,
     /// 转账余额
-    pub transfer_balance: String,
+    #[serde(with = "crate::lenient")]
+    pub transfer_balance: Amount,
     /// 转账余额列表
     pub transfer_balance_list: Vec<OkLinkDetailInscription>,
 }
 
+impl Paginated for OkLinkBalanceDetail {
+    type Item = OkLinkDetailInscription;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    fn items(&self) -> &[OkLinkDetailInscription] {
+        &self.transfer_balance_list
+    }
+
+    fn into_items(self) -> Vec<OkLinkDetailInscription> {
+        self.transfer_balance_list
+    }
+}
+
 // 定义OKLink详细铭文结构体
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -29,18 +51,20 @@ pub struct OkLinkDetailInscription {
     /// 铭文ID
     pub inscription_id: String,
     /// 铭文编号
-    pub inscription_number: String,
+    #[serde(with = "crate::lenient")]
+    pub inscription_number: i64,
     /// 金额
-    pub amount: String,
+    #[serde(with = "crate::lenient")]
+    pub amount: Amount,
 }
 
 // 定义OKLink通用响应结构体
 #[derive(Deserialize, Serialize, Debug)]
-pub struct OkLinkResp<T: Serialize> {
+pub struct OkLinkResp<T> {
     /// 响应码
-    code: String,
+    pub code: String,
     /// 响应消息
-    msg: String,
+    pub msg: String,
     /// 数据列表
     pub data: Vec<T>,
 }
@@ -50,23 +74,37 @@ pub struct OkLinkResp<T: Serialize> {
 #[serde(rename_all = "camelCase")]
 pub struct TokenPrice {
     /// 最后价格
-    pub last_price: String,
+    #[serde(with = "crate::lenient")]
+    pub last_price: Amount,
 }
 
 // 定义OKLink余额分页结构体
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OkLinkBalancePage {
-    /// 当前页码
-    page: String,
-    /// 每页限制数量
-    limit: String,
-    /// 总页数
-    total_page: String,
+    /// 分页信息
+    #[serde(flatten)]
+    pub page_info: PageInfo,
     /// 余额列表
     pub balance_list: Vec<OkLinkBalance>,
 }
 
+impl Paginated for OkLinkBalancePage {
+    type Item = OkLinkBalance;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    fn items(&self) -> &[OkLinkBalance] {
+        &self.balance_list
+    }
+
+    fn into_items(self) -> Vec<OkLinkBalance> {
+        self.balance_list
+    }
+}
+
 // 定义OKLink余额结构体
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -76,15 +114,19 @@ pub struct OkLinkBalance {
     /// 代币类型
     token_type: String,
     /// 余额
-    pub balance: String,
+    #[serde(with = "crate::lenient")]
+    pub balance: Amount,
     /// 可用余额
-    pub available_balance: String,
+    #[serde(with = "crate::lenient")]
+    pub available_balance: Amount,
     /// 转账余额
-    pub transfer_balance: String,
+    #[serde(with = "crate::lenient")]
+    pub transfer_balance: Amount,
 }
 
 // 定义地址摘要结构体
 #[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AddressSummary {
     /// 链全名
     chain_full_name: String,
@@ -97,39 +139,51 @@ pub struct AddressSummary {
     /// 是否为生产者地址
     is_producer_address: bool,
     /// 余额
-    pub balance: String,
+    #[serde(with = "crate::lenient")]
+    pub balance: Amount,
     /// 余额符号
     balance_symbol: String,
     /// 交易计数
-    transaction_count: String,
+    #[serde(with = "crate::lenient")]
+    transaction_count: u64,
     /// 验证状态
     verifying: String,
     /// 发送金额
-    send_amount: String,
+    #[serde(with = "crate::lenient::option", default)]
+    send_amount: Option<Amount>,
     /// 接收金额
-    receive_amount: String,
+    #[serde(with = "crate::lenient::option", default)]
+    receive_amount: Option<Amount>,
     /// 代币金额
-    token_amount: String,
+    #[serde(with = "crate::lenient::option", default)]
+    token_amount: Option<Amount>,
     /// 总代币价值
-    total_token_value: String,
+    #[serde(with = "crate::lenient::option", default)]
+    total_token_value: Option<Amount>,
     /// 创建合约地址
     create_contract_address: String,
     /// 创建合约交易哈希
     create_contract_transaction_hash: String,
     /// 第一笔交易时间
-    pub first_transaction_time: String,
+    #[serde(with = "crate::lenient::option", default)]
+    pub first_transaction_time: Option<Timestamp>,
     /// 最后一笔交易时间
-    last_transaction_time: String,
+    #[serde(with = "crate::lenient::option", default)]
+    last_transaction_time: Option<Timestamp>,
     /// 代币
     token: String,
     /// 带宽
-    bandwidth: String,
+    #[serde(with = "crate::lenient::option", default)]
+    bandwidth: Option<Amount>,
     /// 能量
-    energy: String,
+    #[serde(with = "crate::lenient::option", default)]
+    energy: Option<Amount>,
     /// 投票权
-    voting_rights: String,
+    #[serde(with = "crate::lenient::option", default)]
+    voting_rights: Option<Amount>,
     /// 未领取的投票奖励
-    unclaimed_voting_rewards: String,
+    #[serde(with = "crate::lenient::option", default)]
+    unclaimed_voting_rewards: Option<Amount>,
     /// 是否为AA地址
     is_aa_address: bool,
 }
@@ -140,7 +194,8 @@ pub struct BalanceItem {
     /// 地址
     pub address: String,
     /// 余额
-    pub balance: String,
+    #[serde(with = "crate::lenient")]
+    pub balance: Amount,
 }
 
 // 定义余额列表结构体
@@ -161,7 +216,7 @@ pub struct BalanceMulti {
 }
 
 // 定义发布交易信息结构体
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PublishTxInfo {
     /// 链全名
@@ -171,3 +226,66 @@ pub struct PublishTxInfo {
     /// 交易ID
     pub txid: String,
 }
+
+/// OkLink 支持人员排查问题时使用的请求 ID 可能出现的响应头。
+const REQUEST_ID_HEADERS: &[&str] = &["x-request-id", "x-trace-id"];
+
+/// 带有响应元数据的解析结果，由各接口的 `*_with_meta` 方法返回。
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    /// 解析后的数据
+    pub data: T,
+    /// 状态码、响应头、耗时等元数据
+    pub meta: ResponseMeta,
+}
+
+impl<T> Response<T> {
+    /// 丢弃元数据，只保留解析后的数据。
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    /// 转换数据部分，保留元数据。
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Response<U> {
+        Response {
+            data: f(self.data),
+            meta: self.meta,
+        }
+    }
+}
+
+/// 一次 API 调用的响应元数据。
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// 最后一次尝试的 HTTP 状态码，命中缓存时为 200
+    pub status: StatusCode,
+    /// 最后一次尝试的响应头，命中缓存时为空
+    pub headers: HeaderMap,
+    /// 从第一次发送到收到最终响应的总耗时（包括重试与等待）
+    pub elapsed: Duration,
+    /// 发送的次数，命中缓存时为 0
+    pub attempts: u32,
+    /// 是否来自响应缓存
+    pub from_cache: bool,
+    /// 原始响应体，仅在客户端开启 `with_raw_body(true)` 时保留
+    pub body: Option<Vec<u8>>,
+}
+
+impl ResponseMeta {
+    /// 读取字符串形式的响应头。
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// 服务端返回的请求 ID，提交 OkLink 工单时可以附上。
+    pub fn request_id(&self) -> Option<&str> {
+        REQUEST_ID_HEADERS.iter().find_map(|name| self.header(name))
+    }
+
+    /// 原始响应体的文本形式。
+    pub fn body_text(&self) -> Option<String> {
+        self.body
+            .as_ref()
+            .map(|body| String::from_utf8_lossy(body).into_owned())
+    }
+}
@@ -0,0 +1,202 @@
+use crate::error::{OkLinkError, RATE_LIMIT_CODES};
+use reqwest::StatusCode;
+use std::{collections::hash_map::RandomState, hash::BuildHasher, time::Duration};
+
+/// `RetryPolicy` 描述了请求失败后的重试策略（指数退避 + 随机抖动）。
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// 最大尝试次数（包含第一次请求），为 1 时不重试
+    pub max_attempts: u32,
+    /// 第一次重试前的基础等待时间
+    pub base_delay: Duration,
+    /// 单次等待时间的上限
+    pub max_delay: Duration,
+    /// 抖动比例（0.0 ~ 1.0），实际等待时间在 `[delay * (1 - jitter), delay]` 之间
+    pub jitter: f64,
+    /// 可重试的 HTTP 状态码
+    pub retryable_statuses: Vec<StatusCode>,
+    /// 可重试的 OkLink 业务错误码
+    pub retryable_codes: Vec<String>,
+    /// 是否重试非幂等请求（如 `publish_tx`），默认关闭以避免重复广播
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: 0.5,
+            retryable_statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retryable_codes: RATE_LIMIT_CODES.iter().map(|c| c.to_string()).collect(),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 不进行任何重试的策略。
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_retryable_statuses(mut self, statuses: Vec<StatusCode>) -> Self {
+        self.retryable_statuses = statuses;
+        self
+    }
+
+    pub fn with_retryable_codes(mut self, codes: Vec<String>) -> Self {
+        self.retryable_codes = codes;
+        self
+    }
+
+    pub fn with_retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// 对于给定的请求类型，返回允许的最大尝试次数。
+    pub fn max_attempts_for(&self, idempotent: bool) -> u32 {
+        if idempotent || self.retry_non_idempotent {
+            self.max_attempts.max(1)
+        } else {
+            1
+        }
+    }
+
+    /// 判断一个错误是否值得重试。
+    pub fn is_retryable(&self, err: &OkLinkError) -> bool {
+        match err {
+            OkLinkError::Transport(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+            OkLinkError::Http { status, .. } => self.retryable_statuses.contains(status),
+            OkLinkError::RateLimited { status, code, .. } => {
+                self.retryable_statuses.contains(status)
+                    || code
+                        .as_ref()
+                        .is_some_and(|c| self.retryable_codes.contains(c))
+            }
+            OkLinkError::Api { code, .. } => self.retryable_codes.contains(code),
+            // 所有 Key 都在冷却中，等到最早的 Key 恢复后再试
+            OkLinkError::NoAvailableKey { retry_after } => retry_after.is_some(),
+            _ => false,
+        }
+    }
+
+    /// 计算第 `attempt` 次失败后的等待时间，优先使用服务端的 `Retry-After`。
+    pub fn delay_for(&self, attempt: u32, err: &OkLinkError) -> Duration {
+        if let Some(retry_after) = err.retry_after() {
+            return retry_after;
+        }
+
+        let exp = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(exp).min(self.max_delay);
+        if self.jitter <= 0.0 {
+            return delay;
+        }
+        delay.mul_f64(1.0 - self.jitter * random_unit())
+    }
+}
+
+/// 解析 `Retry-After` 响应头（仅支持秒数形式）。
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+/// 返回 `[0, 1)` 区间内的随机数，仅用于抖动。
+fn random_unit() -> f64 {
+    let bits = RandomState::new().hash_one(std::time::Instant::now());
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn http_error(status: StatusCode, retry_after: Option<Duration>) -> OkLinkError {
+        OkLinkError::Http {
+            status,
+            body: String::new(),
+            retry_after,
+        }
+    }
+
+    #[test]
+    fn test_retryable_classification() {
+        let policy = RetryPolicy::default();
+        assert!(policy.is_retryable(&http_error(StatusCode::BAD_GATEWAY, None)));
+        assert!(!policy.is_retryable(&http_error(StatusCode::BAD_REQUEST, None)));
+        assert!(policy.is_retryable(&OkLinkError::RateLimited {
+            status: StatusCode::OK,
+            code: Some("50011".to_string()),
+            msg: String::new(),
+            retry_after: None,
+        }));
+        assert!(policy.is_retryable(&OkLinkError::NoAvailableKey {
+            retry_after: Some(Duration::from_secs(1)),
+        }));
+        assert!(!policy.is_retryable(&OkLinkError::Api {
+            code: "50038".to_string(),
+            msg: String::new(),
+        }));
+    }
+
+    #[test]
+    fn test_backoff_delay() {
+        let policy = RetryPolicy::default()
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(350))
+            .with_jitter(0.0);
+        let err = http_error(StatusCode::SERVICE_UNAVAILABLE, None);
+        assert_eq!(policy.delay_for(1, &err), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(2, &err), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(3, &err), Duration::from_millis(350));
+
+        let err = http_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            Some(Duration::from_secs(7)),
+        );
+        assert_eq!(policy.delay_for(1, &err), Duration::from_secs(7));
+    }
+
+    #[test]
+    fn test_post_not_retried_by_default() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.max_attempts_for(false), 1);
+        assert_eq!(
+            policy
+                .with_retry_non_idempotent(true)
+                .max_attempts_for(false),
+            3
+        );
+    }
+}
@@ -0,0 +1,361 @@
+use crate::{
+    OkLinkClient,
+    error::Result,
+    page::Paginated,
+    params::{
+        AddressInscriptionListParams, AddressInternalTransactionListParams,
+        AddressNormalTransactionListParams, AddressTokenTransactionListParams,
+        AddressTransactionListParams,
+    },
+    resp::{OkLinkBalance, OkLinkDetailInscription, OkLinkResp},
+    types::{
+        AddressInscription, AddressTransaction, BlockHeader, BlockTransaction,
+        BlockTransactionMulti, InternalTransaction, NormalTransaction, TokenTransaction, UtxoOk,
+    },
+};
+use futures::{
+    Stream, StreamExt,
+    stream::{self, FuturesOrdered},
+};
+use std::{collections::VecDeque, future::Future};
+
+struct PageState<Item, F, Fut: Future> {
+    fetch: F,
+    prefetch: usize,
+    next_page: u32,
+    total_page: Option<u32>,
+    done: bool,
+    in_flight: FuturesOrdered<Fut>,
+    buffer: VecDeque<Item>,
+}
+
+/// 将按页请求的接口转换为逐条返回数据的 `Stream`。
+///
+/// 第一页返回后根据 `totalPage` 并发预取后续页面（最多 `prefetch` 个请求同时进行）；
+/// 到达 `totalPage` 或遇到空页时结束，出现错误时返回该错误并结束。
+pub(crate) fn paginate<P, F, Fut>(prefetch: usize, fetch: F) -> impl Stream<Item = Result<P::Item>>
+where
+    P: Paginated,
+    F: Fn(u32) -> Fut,
+    Fut: Future<Output = Result<OkLinkResp<P>>>,
+{
+    let state: PageState<P::Item, F, Fut> = PageState {
+        fetch,
+        prefetch: prefetch.max(1),
+        next_page: 1,
+        total_page: None,
+        done: false,
+        in_flight: FuturesOrdered::new(),
+        buffer: VecDeque::new(),
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                return Some((Ok(item), state));
+            }
+            if state.done {
+                return None;
+            }
+
+            // 第一页返回之前不知道总页数，只请求一页。
+            let limit = match state.total_page {
+                Some(_) => state.prefetch,
+                None => 1,
+            };
+            while state.in_flight.len() < limit
+                && state
+                    .total_page
+                    .is_none_or(|total| state.next_page <= total)
+            {
+                state.in_flight.push_back((state.fetch)(state.next_page));
+                state.next_page += 1;
+            }
+
+            match state.in_flight.next().await {
+                None => return None,
+                Some(Err(err)) => {
+                    state.done = true;
+                    return Some((Err(err), state));
+                }
+                Some(Ok(resp)) => {
+                    let Some(page) = resp.data.into_iter().next() else {
+                        state.done = true;
+                        continue;
+                    };
+                    state.total_page = Some(page.page_info().total_page);
+                    let items = page.into_items();
+                    if items.is_empty() {
+                        state.done = true;
+                    }
+                    state.buffer.extend(items);
+                }
+            }
+        }
+    })
+}
+
+impl OkLinkClient {
+    /// 以 `Stream` 的形式遍历地址的全部 UTXO。
+    ///
+    /// # 参数
+    /// - `address`: 要查询的地址。
+    /// - `limit`: 每页的大小。
+    /// - `prefetch`: 最多同时请求的页数。
+    pub fn get_btc_utxo_stream<'a>(
+        &'a self,
+        address: &'a str,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<UtxoOk>> + 'a {
+        paginate(prefetch, move |page| {
+            self.get_btc_utxo_oklink(address, page as usize, limit)
+        })
+    }
+
+    /// 以 `Stream` 的形式遍历地址的全部 BRC-20 代币余额。
+    ///
+    /// # 参数
+    /// - `address`: 要查询的地址。
+    /// - `limit`: 每页的大小。
+    /// - `prefetch`: 最多同时请求的页数。
+    pub fn get_brc20_balance_stream<'a>(
+        &'a self,
+        address: &'a str,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<OkLinkBalance>> + 'a {
+        paginate(prefetch, move |page| {
+            self.get_brc20_balance_oklink(address, page as usize, limit)
+        })
+    }
+
+    /// 以 `Stream` 的形式遍历 BRC-20 代币的全部可转账铭文。
+    ///
+    /// # 参数
+    /// - `address`: 要查询的地址。
+    /// - `tick`: 代币符号。
+    /// - `limit`: 每页的大小。
+    /// - `prefetch`: 最多同时请求的页数。
+    pub fn get_brc20_token_detail_stream<'a>(
+        &'a self,
+        address: &'a str,
+        tick: &'a str,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<OkLinkDetailInscription>> + 'a {
+        paginate(prefetch, move |page| {
+            self.get_brc20_token_detail_oklink(address, tick, page as usize, limit)
+        })
+    }
+
+    /// 以 `Stream` 的形式遍历地址持有的全部铭文。
+    ///
+    /// # 参数
+    /// - `address`: 要查询的地址。
+    /// - `limit`: 每页的大小。
+    /// - `prefetch`: 最多同时请求的页数。
+    pub fn address_inscription_list_stream<'a>(
+        &'a self,
+        address: &'a str,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<AddressInscription>> + 'a {
+        paginate(prefetch, move |page| async move {
+            self.query(
+                &AddressInscriptionListParams::new(address)
+                    .with_page(page as usize)
+                    .with_limit(limit),
+            )
+            .await
+        })
+    }
+
+    /// 以 `Stream` 的形式遍历指定区块内的全部交易。
+    ///
+    /// # 参数
+    /// - `height`: 区块高度。
+    /// - `limit`: 每页的大小。
+    /// - `prefetch`: 最多同时请求的页数。
+    pub fn get_btc_transaction_list_stream(
+        &self,
+        height: usize,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<BlockTransaction>> + '_ {
+        paginate(prefetch, move |page| {
+            self.get_btc_transaction_list_oklink(height, page as usize, limit)
+        })
+    }
+
+    /// 以 `Stream` 的形式遍历区块区间内的全部交易。
+    ///
+    /// # 参数
+    /// - `from_block`: 起始区块高度。
+    /// - `to_block`: 结束区块高度。
+    /// - `limit`: 每页的大小。
+    /// - `prefetch`: 最多同时请求的页数。
+    pub fn get_btc_transaction_list_multi_stream(
+        &self,
+        from_block: usize,
+        to_block: usize,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<BlockTransactionMulti>> + '_ {
+        paginate(prefetch, move |page| {
+            self.get_btc_transaction_list_multi_oklink(from_block, to_block, page as usize, limit)
+        })
+    }
+
+    /// 以 `Stream` 的形式从最新区块开始向前遍历区块。
+    ///
+    /// 新区块产生时页面会整体后移，相邻页之间可能出现重复的区块，需要按高度去重。
+    ///
+    /// # 参数
+    /// - `limit`: 每页的大小。
+    /// - `prefetch`: 最多同时请求的页数。
+    pub fn get_block_list_stream(
+        &self,
+        limit: usize,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<BlockHeader>> + '_ {
+        paginate(prefetch, move |page| {
+            self.get_block_list(page as usize, limit)
+        })
+    }
+
+    /// 以 `Stream` 的形式遍历地址的全部交易，`params` 中的页码会被忽略。
+    ///
+    /// # 参数
+    /// - `params`: 查询参数，包括过滤条件与每页的大小。
+    /// - `prefetch`: 最多同时请求的页数。
+    pub fn address_transaction_list_stream(
+        &self,
+        params: AddressTransactionListParams,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<AddressTransaction>> + '_ {
+        paginate(prefetch, move |page| {
+            let params = params.clone().with_page(page as usize);
+            async move { self.query(&params).await }
+        })
+    }
+
+    /// 以 `Stream` 的形式遍历地址的普通交易，`params` 中的页码会被忽略。
+    ///
+    /// # 参数
+    /// - `params`: 查询参数，包括过滤条件与每页的大小。
+    /// - `prefetch`: 最多同时请求的页数。
+    pub fn address_normal_transaction_list_stream(
+        &self,
+        params: AddressNormalTransactionListParams,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<NormalTransaction>> + '_ {
+        paginate(prefetch, move |page| {
+            let params = params.clone().with_page(page as usize);
+            async move { self.query(&params).await }
+        })
+    }
+
+    /// 以 `Stream` 的形式遍历地址的内部交易，`params` 中的页码会被忽略。
+    ///
+    /// # 参数
+    /// - `params`: 查询参数，包括过滤条件与每页的大小。
+    /// - `prefetch`: 最多同时请求的页数。
+    pub fn address_internal_transaction_list_stream(
+        &self,
+        params: AddressInternalTransactionListParams,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<InternalTransaction>> + '_ {
+        paginate(prefetch, move |page| {
+            let params = params.clone().with_page(page as usize);
+            async move { self.query(&params).await }
+        })
+    }
+
+    /// 以 `Stream` 的形式遍历地址的代币转账，`params` 中的页码会被忽略。
+    ///
+    /// # 参数
+    /// - `params`: 查询参数，包括过滤条件与每页的大小。
+    /// - `prefetch`: 最多同时请求的页数。
+    pub fn address_token_transaction_list_stream(
+        &self,
+        params: AddressTokenTransactionListParams,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<TokenTransaction>> + '_ {
+        paginate(prefetch, move |page| {
+            let params = params.clone().with_page(page as usize);
+            async move { self.query(&params).await }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::page::PageInfo;
+    use futures::TryStreamExt;
+    use std::sync::{Arc, Mutex};
+
+    struct TestPage {
+        page_info: PageInfo,
+        items: Vec<u32>,
+    }
+
+    impl Paginated for TestPage {
+        type Item = u32;
+
+        fn page_info(&self) -> &PageInfo {
+            &self.page_info
+        }
+
+        fn items(&self) -> &[u32] {
+            &self.items
+        }
+
+        fn into_items(self) -> Vec<u32> {
+            self.items
+        }
+    }
+
+    fn page(total_page: u32, items: Vec<u32>) -> OkLinkResp<TestPage> {
+        OkLinkResp {
+            code: "0".to_string(),
+            msg: String::new(),
+            data: vec![TestPage {
+                page_info: PageInfo {
+                    total_page,
+                    ..Default::default()
+                },
+                items,
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stops_at_total_page() {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+        let items: Vec<u32> = paginate(3, |p| {
+            requested.lock().unwrap().push(p);
+            async move { Ok(page(3, vec![p * 10, p * 10 + 1])) }
+        })
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![10, 11, 20, 21, 30, 31]);
+        assert_eq!(*requested.lock().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_stops_at_empty_page() {
+        let items: Vec<u32> = paginate(1, |p| async move {
+            let items = if p < 3 { vec![p] } else { vec![] };
+            Ok(page(10, items))
+        })
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![1, 2]);
+    }
+}
@@ -0,0 +1,262 @@
+//! 请求的 `tracing` 与 `metrics` 埋点。
+//!
+//! 每次发送（包括重试）都会创建一个 `oklink.request` span，记录接口、链、尝试次数、
+//! HTTP 状态码、OkLink 错误码与耗时；API Key 只记录脱敏后的形式。
+//!
+//! 启用 `metrics` 特性后还会导出：
+//! - `oklink_requests_total`（counter，标签 `endpoint`、`chain`、`outcome`）
+//! - `oklink_request_duration_seconds`（histogram，标签 `endpoint`、`chain`）
+//! - `oklink_credits_consumed_total`（counter，标签 `endpoint`、`chain`），收到 2xx 响应的请求计 1 次
+//! - `oklink_cache_hits_total`（counter，标签 `endpoint`、`chain`）
+
+use crate::{
+    error::{OkLinkError, Result},
+    key_pool::redact_key,
+};
+use reqwest::Url;
+use serde_json::Value;
+use std::time::Duration;
+use tracing::{Span, field::Empty};
+
+/// 一次 API 调用的描述信息，用于 span 与 metrics 标签。
+#[derive(Debug, Clone)]
+pub(crate) struct RequestMeta {
+    pub method: &'static str,
+    pub endpoint: String,
+    pub chain: String,
+}
+
+impl RequestMeta {
+    /// 从请求路径与请求体中提取接口与链名称。
+    pub(crate) fn new(method: &'static str, uri: &str, body: Option<&Value>) -> Self {
+        let endpoint = uri.split('?').next().unwrap_or_default().to_string();
+
+        let query_chain = Url::parse(&format!("http://localhost{}", uri))
+            .ok()
+            .and_then(|url| {
+                url.query_pairs()
+                    .find(|(k, _)| k == "chainShortName" || k == "chainId")
+                    .map(|(_, v)| v.into_owned())
+            });
+        let body_chain = body
+            .and_then(|b| b.get("chainShortName"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let chain = query_chain
+            .or(body_chain)
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        Self {
+            method,
+            endpoint,
+            chain,
+        }
+    }
+
+    /// 为第 `attempt` 次尝试创建 span。
+    pub(crate) fn span(&self, attempt: u32, api_key: Option<&str>) -> Span {
+        let span = tracing::info_span!(
+            "oklink.request",
+            method = self.method,
+            endpoint = %self.endpoint,
+            chain = %self.chain,
+            attempt,
+            api_key = Empty,
+            status = Empty,
+            code = Empty,
+            latency_ms = Empty,
+        );
+        if let Some(key) = api_key {
+            span.record("api_key", redact_key(key).as_str());
+        }
+        span
+    }
+
+    /// 记录一次尝试的结果。`live` 为 `false` 时（如磁带回放）不计入额度消耗。
+    pub(crate) fn record<T>(&self, span: &Span, latency: Duration, live: bool, result: &Result<T>) {
+        span.record("latency_ms", latency.as_millis() as u64);
+        match result {
+            Ok(_) => {
+                span.record("status", 200u16);
+                span.record("code", "0");
+                tracing::debug!(parent: span, "oklink request succeeded");
+            }
+            Err(err) => {
+                // 业务错误与解析错误都来自 HTTP 200 响应。
+                let status = match err {
+                    OkLinkError::Api { .. } | OkLinkError::Decode { .. } => Some(200),
+                    _ => err.status().map(|s| s.as_u16()),
+                };
+                if let Some(status) = status {
+                    span.record("status", status);
+                }
+                if let Some(code) = err.code() {
+                    span.record("code", code);
+                }
+                tracing::warn!(parent: span, error = %err, "oklink request failed");
+            }
+        }
+
+        #[cfg(feature = "metrics")]
+        {
+            let labels = [
+                ("endpoint", self.endpoint.clone()),
+                ("chain", self.chain.clone()),
+            ];
+            let outcome = match result {
+                Ok(_) => "ok",
+                Err(err) => outcome(err),
+            };
+            metrics::counter!(
+                "oklink_requests_total",
+                "endpoint" => self.endpoint.clone(),
+                "chain" => self.chain.clone(),
+                "outcome" => outcome,
+            )
+            .increment(1);
+            metrics::histogram!("oklink_request_duration_seconds", &labels)
+                .record(latency.as_secs_f64());
+            if live && consumes_credit(result) {
+                metrics::counter!("oklink_credits_consumed_total", &labels).increment(1);
+            }
+        }
+        #[cfg(not(feature = "metrics"))]
+        let _ = live;
+    }
+
+    /// 记录一次缓存命中。
+    pub(crate) fn record_cache_hit(&self) {
+        tracing::debug!(
+            endpoint = %self.endpoint,
+            chain = %self.chain,
+            "oklink response served from cache"
+        );
+        #[cfg(feature = "metrics")]
+        metrics::counter!(
+            "oklink_cache_hits_total",
+            "endpoint" => self.endpoint.clone(),
+            "chain" => self.chain.clone(),
+        )
+        .increment(1);
+    }
+}
+
+/// 错误的分类，用作 `outcome` 标签。
+#[cfg(feature = "metrics")]
+fn outcome(err: &OkLinkError) -> &'static str {
+    match err {
+        OkLinkError::Transport(_) => "transport",
+        OkLinkError::Http { .. } => "http_error",
+        OkLinkError::Api { .. } => "api_error",
+        OkLinkError::RateLimited { .. } => "rate_limited",
+        OkLinkError::Decode { .. } => "decode_error",
+        _ => "client_error",
+    }
+}
+
+/// 收到 2xx 响应的请求会消耗 OkLink 额度。
+#[cfg(feature = "metrics")]
+fn consumes_credit<T>(result: &Result<T>) -> bool {
+    match result {
+        Ok(_) => true,
+        Err(err) => matches!(err, OkLinkError::Api { .. } | OkLinkError::Decode { .. }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{API_KEY, OkLinkTestServer},
+        types::OkApiUri,
+    };
+    use std::sync::{Arc, Mutex};
+    use tracing::{
+        Subscriber,
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+    };
+    use tracing_subscriber::{Layer, layer::Context, prelude::*, registry::LookupSpan};
+
+    /// 收集所有 `oklink.request` span 的字段。
+    #[derive(Clone, Default)]
+    struct Fields(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name().to_string(), format!("{:?}", value)));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name().to_string(), value.to_string()));
+        }
+    }
+
+    impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Fields {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+            if attrs.metadata().name() == "oklink.request" {
+                attrs.record(&mut self.clone());
+            }
+        }
+
+        fn on_record(&self, _: &Id, values: &Record<'_>, _: Context<'_, S>) {
+            values.record(&mut self.clone());
+        }
+    }
+
+    #[test]
+    fn test_meta_from_uri_and_body() {
+        let meta = RequestMeta::new("GET", "/blockchain/info?chainShortName=BTC", None);
+        assert_eq!(meta.endpoint, "/blockchain/info");
+        assert_eq!(meta.chain, "btc");
+
+        let body = serde_json::json!({ "chainShortName": "eth", "signedTx": "0x" });
+        let meta = RequestMeta::new("POST", "/transaction/publish-tx", Some(&body));
+        assert_eq!(meta.chain, "eth");
+    }
+
+    #[tokio::test]
+    async fn test_span_fields() {
+        let fields = Fields::default();
+        let _guard = tracing_subscriber::registry()
+            .with(fields.clone())
+            .set_default();
+
+        let server = OkLinkTestServer::start().await.unwrap();
+        server.push_fault(
+            OkApiUri::ChainInfo,
+            crate::testing::Fault::Api {
+                code: "50038".to_string(),
+                msg: "chain not supported".to_string(),
+            },
+        );
+        let client = server.client().unwrap();
+        client.get_chain_info().await.unwrap_err();
+
+        let fields = fields.0.lock().unwrap().clone();
+        let get = |name: &str| {
+            fields
+                .iter()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.clone())
+        };
+        assert_eq!(get("endpoint").as_deref(), Some("/blockchain/info"));
+        assert_eq!(get("chain").as_deref(), Some("btc"));
+        assert_eq!(get("attempt").as_deref(), Some("1"));
+        assert_eq!(get("status").as_deref(), Some("200"));
+        assert_eq!(get("code").as_deref(), Some("50038"));
+        assert!(get("latency_ms").is_some());
+        assert!(
+            fields.iter().all(|(_, v)| !v.contains(API_KEY)),
+            "api key must be redacted"
+        );
+        assert!(get("api_key").is_some());
+    }
+}
@@ -0,0 +1,1066 @@
+//! 本地 OkLink 测试服务器。
+//!
+//! `OkLinkTestServer` 在 `127.0.0.1` 的随机端口上模拟 OkLink v5 浏览器接口
+//! （[`OkApiUri`] 中列出的全部路由），返回 `fixtures` 目录下的 JSON 样例，
+//! 校验 `Ok-Access-Key` 请求头，并可以注入 HTTP 错误、业务错误、限流和慢响应，
+//! 用于在不访问网络、不需要真实 API Key 的情况下测试 `OkLinkClient`。
+//!
+//! 需要启用 `testing` 特性。
+//!
+//! ```ignore
+//! let server = OkLinkTestServer::start().await?;
+//! server.push_fault(OkApiUri::ChainInfo, Fault::Status(503));
+//! let client = server.client()?;
+//! let info = client.get_chain_info().await?;
+//! assert_eq!(server.requests().len(), 2);
+//! ```
+
+use crate::{
+    OkLinkClient, builder::OkLinkClientBuilder, error::Result, http_client::ACCESS_KEY_HEADER,
+    types::OkApiUri,
+};
+use reqwest::{StatusCode, Url};
+use serde_json::{Value, json};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    io,
+    net::SocketAddr,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+/// 测试服务器默认接受的 API Key。
+pub const API_KEY: &str = "test-api-key";
+
+/// 接口路径的公共前缀，与正式环境一致。
+const PATH_PREFIX: &str = "/api/v5/explorer";
+
+/// 请求头与请求体的最大长度，超出时直接断开连接。
+const MAX_REQUEST_SIZE: usize = 1024 * 1024;
+
+/// 注入到某个接口的一次性故障。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// 返回指定的 HTTP 状态码。
+    Status(u16),
+    /// 返回 HTTP 200，但 `code` 不为 "0"。
+    Api { code: String, msg: String },
+    /// 返回 HTTP 429 与 `code` 50011，可选地带上 `Retry-After`（秒）。
+    RateLimited { retry_after: Option<u64> },
+    /// 延迟指定时间后再返回正常响应。
+    Delay(Duration),
+}
+
+/// 测试服务器收到的一次请求。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    /// HTTP 方法
+    pub method: String,
+    /// 匹配到的接口，未知路径为 `None`
+    pub uri: Option<OkApiUri>,
+    /// 请求路径，不含查询参数
+    pub path: String,
+    /// 查询参数
+    pub query: HashMap<String, String>,
+    /// `Ok-Access-Key` 请求头
+    pub api_key: Option<String>,
+    /// 请求体
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// 返回查询参数 `name` 的值。
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.query.get(name).map(String::as_str)
+    }
+
+    /// 将请求体解析为 JSON。
+    pub fn json(&self) -> Option<Value> {
+        serde_json::from_slice(&self.body).ok()
+    }
+}
+
+#[derive(Debug)]
+struct ServerState {
+    api_keys: HashSet<String>,
+    fixtures: HashMap<OkApiUri, Value>,
+    pages: HashMap<(OkApiUri, u32), Value>,
+    faults: HashMap<OkApiUri, VecDeque<Fault>>,
+    requests: Vec<RecordedRequest>,
+}
+
+/// 模拟 OkLink v5 浏览器接口的本地 HTTP 服务器，`Drop` 时自动停止。
+#[derive(Debug)]
+pub struct OkLinkTestServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<ServerState>>,
+    handle: JoinHandle<()>,
+}
+
+impl OkLinkTestServer {
+    /// 在随机端口上启动服务器，需要在 tokio 运行时中调用。
+    pub async fn start() -> io::Result<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let addr = listener.local_addr()?;
+        let fixtures = OkApiUri::ALL
+            .iter()
+            .map(|uri| (*uri, default_fixture(*uri)))
+            .collect();
+        let state = Arc::new(Mutex::new(ServerState {
+            api_keys: HashSet::from([API_KEY.to_string()]),
+            fixtures,
+            pages: HashMap::new(),
+            faults: HashMap::new(),
+            requests: Vec::new(),
+        }));
+
+        let handle = tokio::spawn(serve(listener, state.clone()));
+        Ok(Self {
+            addr,
+            state,
+            handle,
+        })
+    }
+
+    /// 服务器监听的地址。
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// 可直接传给 `OkLinkClientBuilder::base_url` 的基础地址。
+    pub fn base_url(&self) -> String {
+        format!("http://{}{}", self.addr, PATH_PREFIX)
+    }
+
+    /// 已配置好 `base_url` 与 [`API_KEY`] 的构建器，可以继续修改其他选项。
+    pub fn client_builder(&self) -> OkLinkClientBuilder {
+        OkLinkClient::builder()
+            .base_url(self.base_url())
+            .api_key(API_KEY)
+    }
+
+    /// 使用默认配置连接到本服务器的客户端。
+    pub fn client(&self) -> Result<OkLinkClient> {
+        self.client_builder().build()
+    }
+
+    /// 额外接受一个 API Key。
+    pub fn add_api_key(&self, api_key: impl Into<String>) -> &Self {
+        self.lock().api_keys.insert(api_key.into());
+        self
+    }
+
+    /// 替换某个接口的响应。
+    pub fn set_fixture(&self, uri: OkApiUri, response: Value) -> &Self {
+        self.lock().fixtures.insert(uri, response);
+        self
+    }
+
+    /// 设置某个接口第 `page` 页的响应，优先于 `set_fixture` 设置的响应。
+    pub fn set_page(&self, uri: OkApiUri, page: u32, response: Value) -> &Self {
+        self.lock().pages.insert((uri, page), response);
+        self
+    }
+
+    /// 为某个接口追加一个故障，按先进先出的顺序各生效一次。
+    pub fn push_fault(&self, uri: OkApiUri, fault: Fault) -> &Self {
+        self.lock().faults.entry(uri).or_default().push_back(fault);
+        self
+    }
+
+    /// 返回所有已收到的请求。
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// 返回某个接口收到的请求。
+    pub fn requests_to(&self, uri: OkApiUri) -> Vec<RecordedRequest> {
+        self.lock()
+            .requests
+            .iter()
+            .filter(|r| r.uri == Some(uri))
+            .cloned()
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ServerState> {
+        self.state.lock().unwrap()
+    }
+}
+
+impl Drop for OkLinkTestServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// 返回某个接口内置的 JSON 样例。
+pub fn default_fixture(uri: OkApiUri) -> Value {
+    let raw = match uri {
+        OkApiUri::AddressSummary => include_str!("testing/fixtures/address_summary.json"),
+        OkApiUri::BalanceMulti => include_str!("testing/fixtures/balance_multi.json"),
+        OkApiUri::BtcAddressBalanceList => {
+            include_str!("testing/fixtures/brc20_balance_list.json")
+        }
+        OkApiUri::BtcAddressBalanceDetail => {
+            include_str!("testing/fixtures/brc20_balance_detail.json")
+        }
+        OkApiUri::InscriptionAddressInscriptionList => {
+            include_str!("testing/fixtures/address_inscription_list.json")
+        }
+        OkApiUri::TransactionPublicshTx => include_str!("testing/fixtures/publish_tx.json"),
+        OkApiUri::AddressUtxo => include_str!("testing/fixtures/address_utxo.json"),
+        OkApiUri::BrcTransactionList => include_str!("testing/fixtures/btc_transaction_list.json"),
+        OkApiUri::TokenPriceMarketData => {
+            include_str!("testing/fixtures/token_price_market_data.json")
+        }
+        OkApiUri::BlockTransactionMulti => {
+            include_str!("testing/fixtures/block_transaction_list_multi.json")
+        }
+        OkApiUri::BlockTransaction => include_str!("testing/fixtures/block_transaction_list.json"),
+        OkApiUri::ChainInfo => include_str!("testing/fixtures/chain_info.json"),
+        OkApiUri::BlockchainSummary => include_str!("testing/fixtures/blockchain_summary.json"),
+        OkApiUri::BlockFills => include_str!("testing/fixtures/block_fills.json"),
+        OkApiUri::BlockList => include_str!("testing/fixtures/block_list.json"),
+        OkApiUri::BlockHeightByTime => {
+            include_str!("testing/fixtures/block_height_by_time.json")
+        }
+        OkApiUri::AddressTransaction => {
+            include_str!("testing/fixtures/address_transaction_list.json")
+        }
+        OkApiUri::AddressNormalTransaction => {
+            include_str!("testing/fixtures/address_normal_transaction_list.json")
+        }
+        OkApiUri::AddressInternalTransaction => {
+            include_str!("testing/fixtures/address_internal_transaction_list.json")
+        }
+        OkApiUri::AddressTokenTransaction => {
+            include_str!("testing/fixtures/address_token_transaction_list.json")
+        }
+        OkApiUri::AddressTransactionMulti => {
+            include_str!("testing/fixtures/address_transaction_list_multi.json")
+        }
+        OkApiUri::AddressNormalTransactionMulti => {
+            include_str!("testing/fixtures/address_normal_transaction_list_multi.json")
+        }
+        OkApiUri::AddressInternalTransactionMulti => {
+            include_str!("testing/fixtures/address_internal_transaction_list_multi.json")
+        }
+        OkApiUri::AddressTokenTransactionMulti => {
+            include_str!("testing/fixtures/address_token_transaction_list_multi.json")
+        }
+    };
+    serde_json::from_str(raw).expect("fixture must be valid JSON")
+}
+
+struct Request {
+    method: String,
+    target: String,
+    headers: HashMap<String, String>,
+    body: Vec<u8>,
+}
+
+struct Response {
+    status: StatusCode,
+    body: Value,
+    retry_after: Option<u64>,
+}
+
+impl Response {
+    fn json(status: StatusCode, body: Value) -> Self {
+        Self {
+            status,
+            body,
+            retry_after: None,
+        }
+    }
+
+    fn error(status: StatusCode, code: &str, msg: &str) -> Self {
+        Self::json(status, json!({ "code": code, "msg": msg, "data": [] }))
+    }
+}
+
+async fn serve(listener: TcpListener, state: Arc<Mutex<ServerState>>) {
+    loop {
+        let Ok((stream, _)) = listener.accept().await else {
+            continue;
+        };
+        tokio::spawn(handle_connection(stream, state.clone()));
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, state: Arc<Mutex<ServerState>>) {
+    let Ok(Some(request)) = read_request(&mut stream).await else {
+        return;
+    };
+    let (delay, response) = respond(&state, request);
+    if let Some(delay) = delay {
+        tokio::time::sleep(delay).await;
+    }
+    let _ = write_response(&mut stream, response).await;
+}
+
+/// 记录请求，并根据 API Key、故障队列与样例生成响应。
+fn respond(state: &Mutex<ServerState>, request: Request) -> (Option<Duration>, Response) {
+    let url = Url::parse(&format!("http://localhost{}", request.target)).ok();
+    let path = url
+        .as_ref()
+        .map(|u| u.path().to_string())
+        .unwrap_or_default();
+    let query: HashMap<String, String> = url
+        .as_ref()
+        .map(|u| u.query_pairs().into_owned().collect())
+        .unwrap_or_default();
+    let uri = path.strip_prefix(PATH_PREFIX).and_then(OkApiUri::from_path);
+    let api_key = request
+        .headers
+        .get(&ACCESS_KEY_HEADER.to_ascii_lowercase())
+        .cloned();
+
+    let mut state = state.lock().unwrap();
+    state.requests.push(RecordedRequest {
+        method: request.method.clone(),
+        uri,
+        path,
+        query: query.clone(),
+        api_key: api_key.clone(),
+        body: request.body,
+    });
+
+    let Some(uri) = uri else {
+        return (
+            None,
+            Response::error(StatusCode::NOT_FOUND, "404", "Not Found"),
+        );
+    };
+    let expected_method = match uri {
+        OkApiUri::TransactionPublicshTx => "POST",
+        _ => "GET",
+    };
+    if request.method != expected_method {
+        return (
+            None,
+            Response::error(StatusCode::METHOD_NOT_ALLOWED, "405", "Method Not Allowed"),
+        );
+    }
+    match api_key {
+        None => {
+            return (
+                None,
+                Response::error(
+                    StatusCode::UNAUTHORIZED,
+                    "50103",
+                    "Request header OK-ACCESS-KEY can not be empty.",
+                ),
+            );
+        }
+        Some(key) if !state.api_keys.contains(&key) => {
+            return (
+                None,
+                Response::error(StatusCode::UNAUTHORIZED, "50111", "Invalid OK-ACCESS-KEY."),
+            );
+        }
+        Some(_) => {}
+    }
+
+    let mut delay = None;
+    match state.faults.get_mut(&uri).and_then(VecDeque::pop_front) {
+        Some(Fault::Status(status)) => {
+            let status = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            let msg = status.canonical_reason().unwrap_or_default();
+            return (
+                None,
+                Response::error(status, &status.as_u16().to_string(), msg),
+            );
+        }
+        Some(Fault::Api { code, msg }) => {
+            return (None, Response::error(StatusCode::OK, &code, &msg));
+        }
+        Some(Fault::RateLimited { retry_after }) => {
+            let mut response = Response::error(
+                StatusCode::TOO_MANY_REQUESTS,
+                "50011",
+                "Rate limit reached. Please refer to API documentation.",
+            );
+            response.retry_after = retry_after;
+            return (None, response);
+        }
+        Some(Fault::Delay(d)) => delay = Some(d),
+        None => {}
+    }
+
+    let page = query.get("page").and_then(|p| p.parse::<u32>().ok());
+    let body = page
+        .and_then(|p| state.pages.get(&(uri, p)))
+        .or_else(|| state.fixtures.get(&uri))
+        .cloned()
+        .unwrap_or_else(|| json!({ "code": "0", "msg": "", "data": [] }));
+    (delay, Response::json(StatusCode::OK, body))
+}
+
+/// 读取一个 HTTP/1.1 请求，连接提前关闭时返回 `None`。
+async fn read_request(stream: &mut TcpStream) -> io::Result<Option<Request>> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos;
+        }
+        if buf.len() > MAX_REQUEST_SIZE {
+            return Ok(None);
+        }
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(None);
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let target = request_line.next().unwrap_or_default().to_string();
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+
+    let content_length = headers
+        .get("content-length")
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(0);
+    if content_length > MAX_REQUEST_SIZE {
+        return Ok(None);
+    }
+    let mut body = buf.split_off(header_end + 4);
+    while body.len() < content_length {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(None);
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+    body.truncate(content_length);
+
+    Ok(Some(Request {
+        method,
+        target,
+        headers,
+        body,
+    }))
+}
+
+/// 每个响应都带有递增的 `X-Request-Id` 响应头。
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+async fn write_response(stream: &mut TcpStream, response: Response) -> io::Result<()> {
+    let body = response.body.to_string();
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\nX-Request-Id: req-{}\r\n",
+        response.status.as_u16(),
+        response.status.canonical_reason().unwrap_or_default(),
+        body.len(),
+        NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed)
+    );
+    if let Some(retry_after) = response.retry_after {
+        head.push_str(&format!("Retry-After: {}\r\n", retry_after));
+    }
+    head.push_str("\r\n");
+
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        chain::Chain,
+        error::OkLinkError,
+        params::{
+            AddressSummaryParams, AddressTokenTransactionListParams, AddressUtxoParams,
+            BlockTransactionListParams, Direction, ProtocolType,
+        },
+        retry::RetryPolicy,
+    };
+    use futures::TryStreamExt;
+
+    async fn server() -> OkLinkTestServer {
+        OkLinkTestServer::start().await.unwrap()
+    }
+
+    /// 不重试的客户端，便于直接观察注入的故障。
+    fn no_retry(server: &OkLinkTestServer) -> OkLinkClient {
+        server
+            .client_builder()
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap()
+    }
+
+    fn only_request(server: &OkLinkTestServer, uri: OkApiUri) -> RecordedRequest {
+        let requests = server.requests_to(uri);
+        assert_eq!(requests.len(), 1);
+        let request = requests.into_iter().next().unwrap();
+        assert_eq!(request.api_key.as_deref(), Some(API_KEY));
+        request
+    }
+
+    #[tokio::test]
+    async fn test_get_token_price_market_data() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client.get_token_price_market_data().await.unwrap();
+        assert_eq!(resp["data"][0]["lastPrice"], "67120.5");
+        let request = only_request(&server, OkApiUri::TokenPriceMarketData);
+        assert_eq!(request.param("chainId"), Some("0"));
+    }
+
+    #[tokio::test]
+    async fn test_get_address_summary_oklink() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client
+            .get_address_summary_oklink("1BM1sAcrfV6d4zPKytzziu4McLQDsFC2Qc")
+            .await
+            .unwrap();
+        assert_eq!(resp.data.len(), 1);
+        assert!(resp.data[0].first_transaction_time.is_some());
+        let request = only_request(&server, OkApiUri::AddressSummary);
+        assert_eq!(request.param("chainShortName"), Some("btc"));
+        assert_eq!(
+            request.param("address"),
+            Some("1BM1sAcrfV6d4zPKytzziu4McLQDsFC2Qc")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_address_balance_oklink_multi() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client
+            .get_address_balance_oklink_multi("1BM1sAcrfV6d4zPKytzziu4McLQDsFC2Qc")
+            .await
+            .unwrap();
+        assert_eq!(resp.data[0].symbol, "BTC");
+        assert_eq!(resp.data[0].balance_list.len(), 1);
+        let request = only_request(&server, OkApiUri::BalanceMulti);
+        assert_eq!(
+            request.param("address"),
+            Some("1BM1sAcrfV6d4zPKytzziu4McLQDsFC2Qc")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_brc20_balance_oklink() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client
+            .get_brc20_balance_oklink("bc1qaddress", 1, 20)
+            .await
+            .unwrap();
+        assert_eq!(resp.data[0].balance_list[0].token, "ordi");
+        let request = only_request(&server, OkApiUri::BtcAddressBalanceList);
+        assert_eq!(request.param("address"), Some("bc1qaddress"));
+        assert_eq!(request.param("page"), Some("1"));
+        assert_eq!(request.param("limit"), Some("20"));
+    }
+
+    #[tokio::test]
+    async fn test_get_brc20_token_detail_oklink() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client
+            .get_brc20_token_detail_oklink("bc1qaddress", "ordi", 2, 10)
+            .await
+            .unwrap();
+        assert_eq!(
+            resp.data[0].transfer_balance_list[0].inscription_number,
+            2650438
+        );
+        let request = only_request(&server, OkApiUri::BtcAddressBalanceDetail);
+        assert_eq!(request.param("token"), Some("ordi"));
+        assert_eq!(request.param("page"), Some("2"));
+        assert_eq!(request.param("limit"), Some("10"));
+    }
+
+    #[tokio::test]
+    async fn test_query_values_are_encoded() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let tick = "or di&page=9 日";
+        client
+            .get_brc20_token_detail_oklink("bc1qaddress", tick, 1, 10)
+            .await
+            .unwrap();
+        let request = only_request(&server, OkApiUri::BtcAddressBalanceDetail);
+        assert_eq!(request.param("token"), Some(tick));
+        assert_eq!(request.param("page"), Some("1"));
+    }
+
+    #[tokio::test]
+    async fn test_query_with_params() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let params = BlockTransactionListParams::new(800000)
+            .with_chain("eth")
+            .with_protocol_type(ProtocolType::Token20)
+            .with_limit(50);
+        client.query(&params).await.unwrap();
+        let request = only_request(&server, OkApiUri::BlockTransaction);
+        assert_eq!(request.param("chainShortName"), Some("eth"));
+        assert_eq!(request.param("protocolType"), Some("token_20"));
+        assert_eq!(request.param("page"), Some("1"));
+
+        // 校验失败的参数不会发送请求。
+        let err = client
+            .query(&AddressUtxoParams::new("bc1q").with_limit(500))
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(err, OkLinkError::InvalidRequest(_)));
+        assert!(server.requests_to(OkApiUri::AddressUtxo).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_query_overrides_chain_per_call() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        // 同一个客户端按请求参数访问不同的链，不需要为每条链创建客户端。
+        for chain in [Chain::Eth, Chain::Tron] {
+            client
+                .query(&AddressSummaryParams::new("addr").with_chain(chain))
+                .await
+                .unwrap();
+        }
+        client
+            .query(&AddressSummaryParams::new("addr"))
+            .await
+            .unwrap();
+
+        let chains: Vec<_> = server
+            .requests_to(OkApiUri::AddressSummary)
+            .iter()
+            .map(|r| r.param("chainShortName").unwrap().to_string())
+            .collect();
+        assert_eq!(chains, vec!["ETH", "TRON", "btc"]);
+        assert_eq!(client.chain(), Some(Chain::Btc));
+    }
+
+    #[tokio::test]
+    async fn test_address_inscription_list_oklink() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client
+            .address_inscription_list_oklink("bc1qaddress", 1, 20)
+            .await
+            .unwrap();
+        assert_eq!(resp["data"][0]["inscriptionsList"][0]["token"], "ordi");
+        let request = only_request(&server, OkApiUri::InscriptionAddressInscriptionList);
+        assert_eq!(request.param("protocolType"), Some("brc20"));
+        assert_eq!(request.param("address"), Some("bc1qaddress"));
+    }
+
+    #[tokio::test]
+    async fn test_publish_tx() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client.publish_tx("0200000001abcdef").await.unwrap();
+        assert_eq!(resp.chain_short_name, "BTC");
+        let request = only_request(&server, OkApiUri::TransactionPublicshTx);
+        assert_eq!(request.method, "POST");
+        assert_eq!(
+            request.json().unwrap(),
+            json!({ "chainShortName": "btc", "signedTx": "0200000001abcdef" })
+        );
+
+        server.set_fixture(
+            OkApiUri::TransactionPublicshTx,
+            json!({ "code": "0", "msg": "", "data": [] }),
+        );
+        let err = client.publish_tx("0200000001abcdef").await.unwrap_err();
+        assert!(matches!(err, OkLinkError::Api { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_get_btc_utxo_oklink() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client
+            .get_btc_utxo_oklink("1BM1sAcrfV6d4zPKytzziu4McLQDsFC2Qc", 1, 100)
+            .await
+            .unwrap();
+        assert_eq!(resp.data[0].utxo_list[0].height, Some(756073));
+        let request = only_request(&server, OkApiUri::AddressUtxo);
+        assert_eq!(request.param("page"), Some("1"));
+        assert_eq!(request.param("limit"), Some("100"));
+    }
+
+    #[tokio::test]
+    async fn test_get_utxo_by_inscription_id_oklink() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client
+            .get_utxo_by_inscription_id_oklink("abcdefi0", "2650438")
+            .await
+            .unwrap();
+        assert_eq!(resp.data[0].inscriptions_list[0].block_height, Some(779941));
+        let request = only_request(&server, OkApiUri::BrcTransactionList);
+        assert_eq!(request.param("inscriptionId"), Some("abcdefi0"));
+        assert_eq!(request.param("inscriptionNumber"), Some("2650438"));
+    }
+
+    #[tokio::test]
+    async fn test_get_btc_transaction_list_oklink() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client
+            .get_btc_transaction_list_oklink(812742, 1, 1)
+            .await
+            .unwrap();
+        assert_eq!(resp.data[0].block_list[0].height, 812742);
+        let request = only_request(&server, OkApiUri::BlockTransaction);
+        assert_eq!(request.param("height"), Some("812742"));
+    }
+
+    #[tokio::test]
+    async fn test_get_btc_transaction_list_multi_oklink() {
+        let server = server().await;
+        let client = server.client().unwrap().on_chain(Chain::Eth);
+
+        let resp = client
+            .get_btc_transaction_list_multi_oklink(18809970, 18809972, 1, 1)
+            .await
+            .unwrap();
+        assert_eq!(resp.data[0].transaction_list[0].height, 18809970);
+        let request = only_request(&server, OkApiUri::BlockTransactionMulti);
+        assert_eq!(request.param("chainShortName"), Some("ETH"));
+        assert_eq!(request.param("startBlockHeight"), Some("18809970"));
+        assert_eq!(request.param("endBlockHeight"), Some("18809972"));
+    }
+
+    #[tokio::test]
+    async fn test_get_address_transaction_list() {
+        let server = server().await;
+        let client = server.client().unwrap().on_chain(Chain::Eth);
+        let address = "0x85c6627c4ed773cb7c32644b041f58a058b00d30";
+
+        let resp = client
+            .get_address_transaction_list(address, 1, 1)
+            .await
+            .unwrap();
+        let tx = &resp.data[0].transaction_lists[0];
+        assert_eq!(tx.height, 18809970);
+        assert!(tx.is_to_contract);
+        let request = only_request(&server, OkApiUri::AddressTransaction);
+        assert_eq!(request.param("chainShortName"), Some("ETH"));
+        assert_eq!(request.param("address"), Some(address));
+
+        let resp = client
+            .get_address_normal_transaction_list(address, 1, 1)
+            .await
+            .unwrap();
+        assert_eq!(resp.data[0].transaction_list[0].gas_used, Some(51539));
+        only_request(&server, OkApiUri::AddressNormalTransaction);
+
+        let resp = client
+            .get_address_internal_transaction_list(address, 1, 1)
+            .await
+            .unwrap();
+        assert_eq!(resp.data[0].transaction_list[0].operation, "call");
+        only_request(&server, OkApiUri::AddressInternalTransaction);
+
+        let resp = client
+            .get_address_token_transaction_list(address, ProtocolType::Token20, 1, 1)
+            .await
+            .unwrap();
+        assert_eq!(resp.data[0].transaction_list[0].symbol, "USDT");
+        let request = only_request(&server, OkApiUri::AddressTokenTransaction);
+        assert_eq!(request.param("protocolType"), Some("token_20"));
+    }
+
+    #[tokio::test]
+    async fn test_address_token_transaction_stream() {
+        let server = server().await;
+        let client = server.client().unwrap().on_chain(Chain::Eth);
+        for page in 1..=2u32 {
+            let mut body = default_fixture(OkApiUri::AddressTokenTransaction);
+            body["data"][0]["page"] = json!(page.to_string());
+            body["data"][0]["totalPage"] = json!("2");
+            body["data"][0]["transactionList"][0]["txId"] = json!(format!("0x{}", page));
+            server.set_page(OkApiUri::AddressTokenTransaction, page, body);
+        }
+
+        let params = AddressTokenTransactionListParams::new("0xabc", ProtocolType::Token20)
+            .with_token_contract_address("0xdac17f958d2ee523a2206206994597c13d831ec7")
+            .with_start_block_height(18800000)
+            .with_direction(Direction::To)
+            .with_limit(1);
+        let txids: Vec<_> = client
+            .address_token_transaction_list_stream(params, 2)
+            .map_ok(|tx| tx.tx_id)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(txids, vec!["0x1", "0x2"]);
+
+        // 每一页都带上相同的过滤条件
+        let requests = server.requests_to(OkApiUri::AddressTokenTransaction);
+        assert_eq!(requests.len(), 2);
+        for request in &requests {
+            assert_eq!(request.param("startBlockHeight"), Some("18800000"));
+            assert_eq!(request.param("isFromOrTo"), Some("to"));
+            assert_eq!(request.param("limit"), Some("1"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_block_fills() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client.get_block_fills(812742).await.unwrap();
+        let block = &resp.data[0];
+        assert_eq!(block.height, 812742);
+        assert_eq!(block.txn_count, 3475);
+        assert_eq!(block.block_size, Some(1598293));
+        assert_eq!(block.confirmations, Some(6));
+        assert_eq!(block.gas_used, None);
+        let request = only_request(&server, OkApiUri::BlockFills);
+        assert_eq!(request.param("height"), Some("812742"));
+    }
+
+    #[tokio::test]
+    async fn test_get_block_list() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client.get_block_list(1, 1).await.unwrap();
+        assert_eq!(resp.data[0].block_list[0].height, 812742);
+        assert!(resp.data[0].page_info.has_next_page());
+        let request = only_request(&server, OkApiUri::BlockList);
+        assert_eq!(request.param("chainShortName"), Some("btc"));
+        assert_eq!(request.param("limit"), Some("1"));
+    }
+
+    #[tokio::test]
+    async fn test_block_range_by_time() {
+        let server = server().await;
+        let client = server.client().unwrap().on_chain(Chain::Eth);
+
+        let range = client
+            .block_range_by_time(1696118400000, 1698796799999)
+            .await
+            .unwrap();
+        assert_eq!(range, (812742, 812742));
+
+        let requests = server.requests_to(OkApiUri::BlockHeightByTime);
+        assert_eq!(requests.len(), 2);
+        let find = |closest: &str| {
+            requests
+                .iter()
+                .find(|r| r.param("closest") == Some(closest))
+                .unwrap()
+        };
+        assert_eq!(find("after").param("time"), Some("1696118400000"));
+        assert_eq!(find("before").param("time"), Some("1698796799999"));
+        assert_eq!(find("before").param("chainShortName"), Some("ETH"));
+
+        let err = client.block_range_by_time(2, 1).await.unwrap_err();
+        assert!(matches!(err, OkLinkError::InvalidRequest(_)));
+        assert_eq!(server.requests_to(OkApiUri::BlockHeightByTime).len(), 2);
+
+        server.set_fixture(
+            OkApiUri::BlockHeightByTime,
+            json!({ "code": "0", "msg": "", "data": [] }),
+        );
+        let err = client.block_range_by_time(1, 2).await.unwrap_err();
+        assert!(matches!(err, OkLinkError::InvalidRequest(_)));
+    }
+
+    #[tokio::test]
+    async fn test_get_chain_info() {
+        let server = server().await;
+        let client = server.client().unwrap();
+
+        let resp = client.get_chain_info().await.unwrap();
+        assert_eq!(resp.data[0].last_height, 812742);
+        only_request(&server, OkApiUri::ChainInfo);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_missing_and_unknown_keys() {
+        let server = server().await;
+        let client = no_retry(&server);
+        let other = server
+            .client_builder()
+            .api_key("someone-else")
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+
+        let err = other.get_chain_info().await.unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::UNAUTHORIZED));
+
+        server.add_api_key("someone-else");
+        other.get_chain_info().await.unwrap();
+        client.get_chain_info().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_injected_errors() {
+        let server = server().await;
+        let client = no_retry(&server);
+        server
+            .push_fault(OkApiUri::ChainInfo, Fault::Status(503))
+            .push_fault(
+                OkApiUri::ChainInfo,
+                Fault::Api {
+                    code: "50038".to_string(),
+                    msg: "chain not supported".to_string(),
+                },
+            )
+            .push_fault(
+                OkApiUri::ChainInfo,
+                Fault::RateLimited {
+                    retry_after: Some(7),
+                },
+            );
+
+        let err = client.get_chain_info().await.unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+        let err = client.get_chain_info().await.unwrap_err();
+        assert!(matches!(err, OkLinkError::Api { ref code, .. } if code == "50038"));
+        let err = client.get_chain_info().await.unwrap_err();
+        assert!(err.is_rate_limited());
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(7)));
+        client.get_chain_info().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_retries_injected_faults() {
+        let server = server().await;
+        let client = server
+            .client_builder()
+            .retry_policy(RetryPolicy::default().with_base_delay(Duration::from_millis(1)))
+            .build()
+            .unwrap();
+        server
+            .push_fault(OkApiUri::AddressUtxo, Fault::Status(502))
+            .push_fault(
+                OkApiUri::AddressUtxo,
+                Fault::RateLimited {
+                    retry_after: Some(0),
+                },
+            );
+
+        client
+            .get_btc_utxo_oklink("bc1qaddress", 1, 100)
+            .await
+            .unwrap();
+        assert_eq!(server.requests_to(OkApiUri::AddressUtxo).len(), 3);
+
+        // POST 默认不重试。
+        server.push_fault(OkApiUri::TransactionPublicshTx, Fault::Status(502));
+        assert!(client.publish_tx("0200").await.is_err());
+        assert_eq!(server.requests_to(OkApiUri::TransactionPublicshTx).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_slow_response_times_out() {
+        let server = server().await;
+        let client = server
+            .client_builder()
+            .timeout(Duration::from_millis(50))
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+        server.push_fault(OkApiUri::ChainInfo, Fault::Delay(Duration::from_secs(5)));
+
+        let err = client.get_chain_info().await.unwrap_err();
+        assert!(matches!(err, OkLinkError::Transport(ref e) if e.is_timeout()));
+        client.get_chain_info().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_page_fixtures_drive_streams() {
+        let server = server().await;
+        let client = server.client().unwrap();
+        for page in 1..=2 {
+            server.set_page(
+                OkApiUri::AddressUtxo,
+                page,
+                json!({
+                    "code": "0",
+                    "msg": "",
+                    "data": [{
+                        "page": page.to_string(),
+                        "limit": "1",
+                        "totalPage": "2",
+                        "utxoList": [{
+                            "txid": format!("tx{}", page),
+                            "height": "",
+                            "blockTime": "",
+                            "address": "bc1qaddress",
+                            "unspentAmount": "0.1",
+                            "index": "0"
+                        }]
+                    }]
+                }),
+            );
+        }
+
+        let utxos: Vec<_> = client
+            .get_btc_utxo_stream("bc1qaddress", 1, 2)
+            .try_collect()
+            .await
+            .unwrap();
+        let txids: Vec<_> = utxos.iter().map(|u| u.txid.as_str()).collect();
+        assert_eq!(txids, vec!["tx1", "tx2"]);
+    }
+
+    #[tokio::test]
+    async fn test_block_list_stream() {
+        let server = server().await;
+        let client = server.client().unwrap();
+        for page in 1..=3u64 {
+            let mut body = default_fixture(OkApiUri::BlockList);
+            body["data"][0]["page"] = json!(page.to_string());
+            body["data"][0]["totalPage"] = json!("3");
+            body["data"][0]["blockList"][0]["height"] = json!((812743 - page).to_string());
+            server.set_page(OkApiUri::BlockList, page as u32, body);
+        }
+
+        let heights: Vec<_> = client
+            .get_block_list_stream(1, 2)
+            .map_ok(|block| block.height)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(heights, vec![812742, 812741, 812740]);
+        assert_eq!(server.requests_to(OkApiUri::BlockList).len(), 3);
+    }
+}
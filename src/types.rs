@@ -1,3 +1,7 @@
+use crate::{
+    lenient::{Amount, Timestamp},
+    page::{PageInfo, Paginated},
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -11,9 +15,12 @@ pub struct OkRespBalance {
 pub struct OkDetail {
     pub token: String,
     pub token_type: String,
-    pub balance: String,
-    pub available_balance: String,
-    pub transfer_balance: String,
+    #[serde(with = "crate::lenient")]
+    pub balance: Amount,
+    #[serde(with = "crate::lenient")]
+    pub available_balance: Amount,
+    #[serde(with = "crate::lenient")]
+    pub transfer_balance: Amount,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -24,33 +31,68 @@ pub struct OkResp {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UtxoList {
-    page: String,
-    limit: String,
-    total_page: String,
+    #[serde(flatten)]
+    pub page_info: PageInfo,
     pub utxo_list: Vec<UtxoOk>,
 }
 
+impl Paginated for UtxoList {
+    type Item = UtxoOk;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    fn items(&self) -> &[UtxoOk] {
+        &self.utxo_list
+    }
+
+    fn into_items(self) -> Vec<UtxoOk> {
+        self.utxo_list
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UtxoOk {
     pub txid: String,
-    pub height: String,
-    pub block_time: String,
+    #[serde(with = "crate::lenient::option", default)]
+    pub height: Option<u64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub block_time: Option<Timestamp>,
     pub address: String,
-    pub unspent_amount: String,
-    pub index: String,
+    #[serde(with = "crate::lenient")]
+    pub unspent_amount: Amount,
+    #[serde(with = "crate::lenient")]
+    pub index: u64,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InscriptionOk {
-    page: String,
-    limit: String,
-    total_page: String,
-    total_inscription: String,
+    #[serde(flatten)]
+    pub page_info: PageInfo,
+    #[serde(with = "crate::lenient")]
+    total_inscription: u64,
     pub inscriptions_list: Vec<InscriptionData>,
 }
 
+impl Paginated for InscriptionOk {
+    type Item = InscriptionData;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    fn items(&self) -> &[InscriptionData] {
+        &self.inscriptions_list
+    }
+
+    fn into_items(self) -> Vec<InscriptionData> {
+        self.inscriptions_list
+    }
+}
+
 /*
 {
             "page": "1",
@@ -82,57 +124,471 @@ pub struct InscriptionOk {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BlockTransactionList {
-    pub page: String,
-    pub limit: String,
-    pub total_page: String,
+    #[serde(flatten)]
+    pub page_info: PageInfo,
     pub chain_full_name: String,
     pub chain_short_name: String,
     pub block_list: Vec<BlockTransaction>,
 }
 
+impl Paginated for BlockTransactionList {
+    type Item = BlockTransaction;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    fn items(&self) -> &[BlockTransaction] {
+        &self.block_list
+    }
+
+    fn into_items(self) -> Vec<BlockTransaction> {
+        self.block_list
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BlockTransaction {
     pub txid: String,
     pub method_id: String,
     pub block_hash: String,
-    pub height: String,
-    pub transaction_time: String,
+    #[serde(with = "crate::lenient")]
+    pub height: u64,
+    #[serde(with = "crate::lenient")]
+    pub transaction_time: Timestamp,
     pub from: String,
     pub is_from_contract: bool,
     pub is_to_contract: bool,
     pub to: String,
-    pub amount: String,
+    #[serde(with = "crate::lenient")]
+    pub amount: Amount,
     pub transaction_symbol: String,
-    pub txfee: String,
+    #[serde(with = "crate::lenient")]
+    pub txfee: Amount,
     pub state: String,
     pub token_id: String,
     pub token_contract_address: String,
 }
 
+/// `/address/transaction-list` 的一页交易。
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressTransactionList {
+    #[serde(flatten)]
+    pub page_info: PageInfo,
+    #[serde(default)]
+    pub chain_full_name: String,
+    #[serde(default)]
+    pub chain_short_name: String,
+    pub transaction_lists: Vec<AddressTransaction>,
+}
+
+impl Paginated for AddressTransactionList {
+    type Item = AddressTransaction;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    fn items(&self) -> &[AddressTransaction] {
+        &self.transaction_lists
+    }
+
+    fn into_items(self) -> Vec<AddressTransaction> {
+        self.transaction_lists
+    }
+}
+
+/// 地址的一笔交易，`protocol_type` 为代币转账时 `token_*` 字段有值。
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressTransaction {
+    pub tx_id: String,
+    #[serde(default)]
+    pub method_id: String,
+    #[serde(default)]
+    pub block_hash: String,
+    #[serde(with = "crate::lenient")]
+    pub height: u64,
+    #[serde(with = "crate::lenient")]
+    pub transaction_time: Timestamp,
+    /// 发送方，UTXO 链上有多个时以逗号分隔
+    pub from: String,
+    /// 接收方，UTXO 链上有多个时以逗号分隔
+    pub to: String,
+    #[serde(default)]
+    pub is_from_contract: bool,
+    #[serde(default)]
+    pub is_to_contract: bool,
+    #[serde(with = "crate::lenient")]
+    pub amount: Amount,
+    #[serde(default)]
+    pub transaction_symbol: String,
+    #[serde(with = "crate::lenient::option", default)]
+    pub tx_fee: Option<Amount>,
+    #[serde(default)]
+    pub state: String,
+    #[serde(default)]
+    pub token_id: String,
+    #[serde(default)]
+    pub token_contract_address: String,
+}
+
+/// `/address/normal-transaction-list` 的一页交易，`/address/transaction-list-multi` 与
+/// `/address/normal-transaction-list-multi` 也返回这个结构。
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalTransactionList {
+    #[serde(flatten)]
+    pub page_info: PageInfo,
+    #[serde(default)]
+    pub chain_full_name: String,
+    #[serde(default)]
+    pub chain_short_name: String,
+    pub transaction_list: Vec<NormalTransaction>,
+}
+
+impl Paginated for NormalTransactionList {
+    type Item = NormalTransaction;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    fn items(&self) -> &[NormalTransaction] {
+        &self.transaction_list
+    }
+
+    fn into_items(self) -> Vec<NormalTransaction> {
+        self.transaction_list
+    }
+}
+
+/// 地址的一笔普通交易。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalTransaction {
+    pub tx_id: String,
+    #[serde(default)]
+    pub method_id: String,
+    #[serde(default)]
+    pub nonce: String,
+    #[serde(with = "crate::lenient::option", default)]
+    pub gas_price: Option<Amount>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub gas_limit: Option<u64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub gas_used: Option<u64>,
+    #[serde(default)]
+    pub block_hash: String,
+    #[serde(with = "crate::lenient")]
+    pub height: u64,
+    #[serde(with = "crate::lenient")]
+    pub transaction_time: Timestamp,
+    pub from: String,
+    pub to: String,
+    #[serde(default)]
+    pub is_from_contract: bool,
+    #[serde(default)]
+    pub is_to_contract: bool,
+    #[serde(with = "crate::lenient")]
+    pub amount: Amount,
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(with = "crate::lenient::option", default)]
+    pub tx_fee: Option<Amount>,
+    #[serde(default)]
+    pub state: String,
+    #[serde(default)]
+    pub transaction_type: String,
+}
+
+/// `/address/internal-transaction-list`（及其 `-multi` 版本）的一页内部交易。
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalTransactionList {
+    #[serde(flatten)]
+    pub page_info: PageInfo,
+    #[serde(default)]
+    pub chain_full_name: String,
+    #[serde(default)]
+    pub chain_short_name: String,
+    pub transaction_list: Vec<InternalTransaction>,
+}
+
+impl Paginated for InternalTransactionList {
+    type Item = InternalTransaction;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    fn items(&self) -> &[InternalTransaction] {
+        &self.transaction_list
+    }
+
+    fn into_items(self) -> Vec<InternalTransaction> {
+        self.transaction_list
+    }
+}
+
+/// 地址的一笔内部交易（合约调用产生的原生代币转账）。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalTransaction {
+    pub tx_id: String,
+    /// 调用类型，如 `call`、`create`
+    #[serde(default)]
+    pub operation: String,
+    #[serde(default)]
+    pub block_hash: String,
+    #[serde(with = "crate::lenient")]
+    pub height: u64,
+    #[serde(with = "crate::lenient")]
+    pub transaction_time: Timestamp,
+    pub from: String,
+    pub to: String,
+    #[serde(default)]
+    pub is_from_contract: bool,
+    #[serde(default)]
+    pub is_to_contract: bool,
+    #[serde(with = "crate::lenient")]
+    pub amount: Amount,
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(default)]
+    pub state: String,
+}
+
+/// `/address/token-transaction-list`（及其 `-multi` 版本）的一页代币转账。
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenTransactionList {
+    #[serde(flatten)]
+    pub page_info: PageInfo,
+    #[serde(default)]
+    pub chain_full_name: String,
+    #[serde(default)]
+    pub chain_short_name: String,
+    pub transaction_list: Vec<TokenTransaction>,
+}
+
+impl Paginated for TokenTransactionList {
+    type Item = TokenTransaction;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    fn items(&self) -> &[TokenTransaction] {
+        &self.transaction_list
+    }
+
+    fn into_items(self) -> Vec<TokenTransaction> {
+        self.transaction_list
+    }
+}
+
+/// 地址的一笔代币转账。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenTransaction {
+    pub tx_id: String,
+    #[serde(default)]
+    pub block_hash: String,
+    #[serde(with = "crate::lenient")]
+    pub height: u64,
+    #[serde(with = "crate::lenient")]
+    pub transaction_time: Timestamp,
+    pub from: String,
+    pub to: String,
+    pub token_contract_address: String,
+    /// NFT 的 token ID，同质化代币为空
+    #[serde(default)]
+    pub token_id: String,
+    #[serde(with = "crate::lenient")]
+    pub amount: Amount,
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(default)]
+    pub is_from_contract: bool,
+    #[serde(default)]
+    pub is_to_contract: bool,
+}
+
+/// `/block/block-fills` 返回的区块详情。
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockFills {
+    pub chain_full_name: String,
+    pub chain_short_name: String,
+    pub hash: String,
+    #[serde(with = "crate::lenient")]
+    pub height: u64,
+    /// 出块的验证者或矿池
+    #[serde(default)]
+    pub validator: String,
+    /// 出块地址
+    #[serde(default)]
+    pub miner: String,
+    #[serde(with = "crate::lenient")]
+    pub block_time: Timestamp,
+    #[serde(with = "crate::lenient")]
+    pub txn_count: u64,
+    /// 区块内交易的总金额
+    #[serde(with = "crate::lenient::option", default)]
+    pub amount: Option<Amount>,
+    /// 区块大小（字节）
+    #[serde(with = "crate::lenient::option", default)]
+    pub block_size: Option<u64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub mine_reward: Option<Amount>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub total_fee: Option<Amount>,
+    #[serde(default)]
+    pub fee_symbol: String,
+    #[serde(default)]
+    pub merkle_root_hash: String,
+    #[serde(with = "crate::lenient::option", default)]
+    pub ommer_block: Option<u64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub gas_used: Option<u64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub gas_limit: Option<u64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub gas_avg_price: Option<Amount>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub base_fee_per_gas: Option<Amount>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub burnt: Option<Amount>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub txn_internal: Option<u64>,
+    #[serde(rename = "difficuity", default)]
+    pub difficulty: String,
+    #[serde(default)]
+    pub nonce: String,
+    #[serde(default)]
+    pub state: String,
+    /// 确认数，随新区块产生而增加
+    #[serde(rename = "confirm", with = "crate::lenient::option", default)]
+    pub confirmations: Option<u64>,
+}
+
+/// `/block/block-list` 的一页区块。
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockList {
+    #[serde(flatten)]
+    pub page_info: PageInfo,
+    pub chain_full_name: String,
+    pub chain_short_name: String,
+    pub block_list: Vec<BlockHeader>,
+}
+
+impl Paginated for BlockList {
+    type Item = BlockHeader;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    fn items(&self) -> &[BlockHeader] {
+        &self.block_list
+    }
+
+    fn into_items(self) -> Vec<BlockHeader> {
+        self.block_list
+    }
+}
+
+/// 区块列表中的一个区块，字段是 [`BlockFills`] 的子集。
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHeader {
+    pub hash: String,
+    #[serde(with = "crate::lenient")]
+    pub height: u64,
+    #[serde(default)]
+    pub validator: String,
+    #[serde(with = "crate::lenient")]
+    pub block_time: Timestamp,
+    #[serde(with = "crate::lenient")]
+    pub txn_count: u64,
+    #[serde(with = "crate::lenient::option", default)]
+    pub block_size: Option<u64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub mine_reward: Option<Amount>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub total_fee: Option<Amount>,
+    #[serde(default)]
+    pub fee_symbol: String,
+    #[serde(with = "crate::lenient::option", default)]
+    pub ommer_block: Option<u64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub gas_used: Option<u64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub gas_limit: Option<u64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub gas_avg_price: Option<Amount>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub burnt: Option<Amount>,
+    #[serde(default)]
+    pub state: String,
+}
+
+/// `/block/block-height-by-time` 返回的区块。
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHeightByTime {
+    #[serde(with = "crate::lenient")]
+    pub height: u64,
+    #[serde(with = "crate::lenient")]
+    pub block_time: Timestamp,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BlockTransactionListMulti {
-    pub page: String,
-    pub limit: String,
-    pub total_page: String,
+    #[serde(flatten)]
+    pub page_info: PageInfo,
     pub transaction_list: Vec<BlockTransactionMulti>,
 }
 
+impl Paginated for BlockTransactionListMulti {
+    type Item = BlockTransactionMulti;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    fn items(&self) -> &[BlockTransactionMulti] {
+        &self.transaction_list
+    }
+
+    fn into_items(self) -> Vec<BlockTransactionMulti> {
+        self.transaction_list
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BlockTransactionMulti {
-    pub height: String,
+    #[serde(with = "crate::lenient")]
+    pub height: u64,
     pub tx_id: String,
     pub block_hash: String,
-    pub transaction_time: String,
+    #[serde(with = "crate::lenient")]
+    pub transaction_time: Timestamp,
     pub from: String,
     pub is_from_contract: bool,
     pub is_to_contract: bool,
     pub to: String,
-    pub amount: String,
+    #[serde(with = "crate::lenient")]
+    pub amount: Amount,
     pub transaction_symbol: String,
-    pub tx_fee: String,
+    #[serde(with = "crate::lenient")]
+    pub tx_fee: Amount,
     pub state: String,
     pub token_id: String,
     pub token_contract_address: String,
@@ -142,20 +598,81 @@ pub struct BlockTransactionMulti {
 #[serde(rename_all = "camelCase")]
 pub struct InscriptionData {
     pub tx_id: String,
-    pub block_height: String,
+    #[serde(with = "crate::lenient::option", default)]
+    pub block_height: Option<u64>,
     pub state: String,
     pub token_type: String,
     pub action_type: String,
     pub from_address: String,
     pub to_address: String,
-    pub amount: String,
+    #[serde(with = "crate::lenient::option", default)]
+    pub amount: Option<Amount>,
     pub token: String,
     pub inscription_id: String,
-    pub inscription_number: String,
-    pub index: String,
+    #[serde(with = "crate::lenient")]
+    pub inscription_number: i64,
+    #[serde(with = "crate::lenient::option", default)]
+    pub index: Option<u64>,
     pub location: String,
-    pub  msg: String,
-    pub  time: String,
+    pub msg: String,
+    #[serde(with = "crate::lenient")]
+    pub time: Timestamp,
+}
+
+// 地址持有的铭文列表
+// /inscription/address-inscription-list
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressInscriptionList {
+    #[serde(flatten)]
+    pub page_info: PageInfo,
+    #[serde(with = "crate::lenient::option", default)]
+    pub total_inscription: Option<u64>,
+    pub inscriptions_list: Vec<AddressInscription>,
+}
+
+impl Paginated for AddressInscriptionList {
+    type Item = AddressInscription;
+
+    fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+
+    fn items(&self) -> &[AddressInscription] {
+        &self.inscriptions_list
+    }
+
+    fn into_items(self) -> Vec<AddressInscription> {
+        self.inscriptions_list
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressInscription {
+    pub inscription_id: String,
+    #[serde(with = "crate::lenient")]
+    pub inscription_number: i64,
+    #[serde(default)]
+    pub location: String,
+    #[serde(default)]
+    pub token: String,
+    #[serde(default)]
+    pub state: String,
+    #[serde(default)]
+    pub msg: String,
+    #[serde(default)]
+    pub token_type: String,
+    #[serde(default)]
+    pub action_type: String,
+    #[serde(default)]
+    pub owner_address: String,
+    #[serde(default)]
+    pub tx_id: String,
+    #[serde(with = "crate::lenient::option", default)]
+    pub block_height: Option<u64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub time: Option<Timestamp>,
 }
 
 // ChainInfo
@@ -183,23 +700,61 @@ pub struct BlockChainInfo {
     pub chain_full_name: String,
     pub chain_short_name: String,
     pub symbol: String,
-    pub rank: String,
+    #[serde(with = "crate::lenient")]
+    pub rank: u64,
     pub mineable: bool,
     pub algorithm: String,
     pub consensus: String,
     pub diff_estimation: String,
     pub current_diff: String,
-    pub diff_adjust_time: String,
-    pub circulating_supply: String,
-    pub total_supply: String,
-    pub tps: String,
-    pub last_height: String,
-    pub last_block_time: String,
-    pub issue_date: String,
+    #[serde(with = "crate::lenient::option", default)]
+    pub diff_adjust_time: Option<Timestamp>,
+    #[serde(with = "crate::lenient")]
+    pub circulating_supply: Amount,
+    #[serde(with = "crate::lenient::option", default)]
+    pub total_supply: Option<Amount>,
+    #[serde(with = "crate::lenient")]
+    pub tps: f64,
+    #[serde(with = "crate::lenient")]
+    pub last_height: u64,
+    #[serde(with = "crate::lenient")]
+    pub last_block_time: Timestamp,
+    #[serde(with = "crate::lenient::option", default)]
+    pub issue_date: Option<Timestamp>,
+}
 
+// BlockchainSummary
+/*
+            "chainFullName": "Bitcoin",
+            "chainShortName": "BTC",
+            "symbol": "BTC",
+            "lastHeight": "812742",
+            "lastBlockTime": "1697623175000",
+            "circulatingSupply": "19517050",
+            "circulatingSupplyProportion": "0.9294",
+            "transactions": "904278893"
+*/
+/// `/blockchain/summary` 返回的单条链概况，不指定链时返回 OkLink 支持的全部链。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockchainSummary {
+    pub chain_full_name: String,
+    pub chain_short_name: String,
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(with = "crate::lenient::option", default)]
+    pub last_height: Option<u64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub last_block_time: Option<Timestamp>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub circulating_supply: Option<Amount>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub circulating_supply_proportion: Option<f64>,
+    #[serde(with = "crate::lenient::option", default)]
+    pub transactions: Option<u64>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum OkApiUri {
     AddressSummary,
     BalanceMulti,
@@ -212,10 +767,59 @@ pub enum OkApiUri {
     TokenPriceMarketData,
     BlockTransactionMulti,
     BlockTransaction,
-    ChainInfo
+    ChainInfo,
+    BlockchainSummary,
+    BlockFills,
+    BlockList,
+    BlockHeightByTime,
+    AddressTransaction,
+    AddressNormalTransaction,
+    AddressInternalTransaction,
+    AddressTokenTransaction,
+    AddressTransactionMulti,
+    AddressNormalTransactionMulti,
+    AddressInternalTransactionMulti,
+    AddressTokenTransactionMulti,
 }
 
 impl OkApiUri {
+    /// 所有已封装的接口。
+    pub const ALL: &'static [OkApiUri] = &[
+        OkApiUri::AddressSummary,
+        OkApiUri::BalanceMulti,
+        OkApiUri::BtcAddressBalanceList,
+        OkApiUri::BtcAddressBalanceDetail,
+        OkApiUri::InscriptionAddressInscriptionList,
+        OkApiUri::TransactionPublicshTx,
+        OkApiUri::AddressUtxo,
+        OkApiUri::BrcTransactionList,
+        OkApiUri::TokenPriceMarketData,
+        OkApiUri::BlockTransactionMulti,
+        OkApiUri::BlockTransaction,
+        OkApiUri::ChainInfo,
+        OkApiUri::BlockchainSummary,
+        OkApiUri::BlockFills,
+        OkApiUri::BlockList,
+        OkApiUri::BlockHeightByTime,
+        OkApiUri::AddressTransaction,
+        OkApiUri::AddressNormalTransaction,
+        OkApiUri::AddressInternalTransaction,
+        OkApiUri::AddressTokenTransaction,
+        OkApiUri::AddressTransactionMulti,
+        OkApiUri::AddressNormalTransactionMulti,
+        OkApiUri::AddressInternalTransactionMulti,
+        OkApiUri::AddressTokenTransactionMulti,
+    ];
+
+    /// 根据相对路径（可带查询参数）找到对应的接口，如 `/blockchain/info?chainShortName=btc`。
+    pub fn from_path(path: &str) -> Option<OkApiUri> {
+        let path = path.split('?').next().unwrap_or_default();
+        OkApiUri::ALL
+            .iter()
+            .copied()
+            .find(|uri| uri.as_str() == path)
+    }
+
     pub fn as_str(&self) -> &str {
         match self {
             OkApiUri::AddressSummary => "/address/address-summary",
@@ -229,7 +833,19 @@ impl OkApiUri {
             OkApiUri::TokenPriceMarketData => "/tokenprice/market-data",
             OkApiUri::BlockTransactionMulti => "/block/transaction-list-multi",
             OkApiUri::BlockTransaction => "/block/transaction-list",
-            OkApiUri::ChainInfo => "/blockchain/info"
+            OkApiUri::ChainInfo => "/blockchain/info",
+            OkApiUri::BlockchainSummary => "/blockchain/summary",
+            OkApiUri::BlockFills => "/block/block-fills",
+            OkApiUri::BlockList => "/block/block-list",
+            OkApiUri::BlockHeightByTime => "/block/block-height-by-time",
+            OkApiUri::AddressTransaction => "/address/transaction-list",
+            OkApiUri::AddressNormalTransaction => "/address/normal-transaction-list",
+            OkApiUri::AddressInternalTransaction => "/address/internal-transaction-list",
+            OkApiUri::AddressTokenTransaction => "/address/token-transaction-list",
+            OkApiUri::AddressTransactionMulti => "/address/transaction-list-multi",
+            OkApiUri::AddressNormalTransactionMulti => "/address/normal-transaction-list-multi",
+            OkApiUri::AddressInternalTransactionMulti => "/address/internal-transaction-list-multi",
+            OkApiUri::AddressTokenTransactionMulti => "/address/token-transaction-list-multi",
         }
     }
 }